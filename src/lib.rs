@@ -1,7 +1,9 @@
 mod programs;
+pub mod transaction;
 
 use serde::{Serialize, Deserialize};
 use solana_sdk::instruction::CompiledInstruction;
+use thiserror::Error;
 use tokio::spawn;
 use tracing::info;
 
@@ -33,6 +35,31 @@ pub struct InstructionFunction {
     pub program: String,
     // Which function is this function? (Well duh)
     pub function_name: String,
+    // What the function does across programs, dot separated from the most general to the most
+    // specific. An empty string means the function is unclassified (configuration, bookkeeping,
+    // ...). Undoing an action ends in ".cancel": it replaces the last segment when that segment is
+    // the verb (order.place becomes order.cancel) and is appended when the action is a bare verb
+    // (list becomes list.cancel). The vocabulary:
+    //   dex: swap, pool.create, market.create, liquidity.add, liquidity.remove, position.open,
+    //        position.close, position.collect, order.place, order.cancel, order.match,
+    //        order.settle
+    //   farm: deposit, withdraw, harvest
+    //   governance: deposit, withdraw, proposal.create, proposal.cancel, proposal.execute, vote,
+    //               vote.relinquish
+    //   lending: deposit, withdraw, collateral.deposit, collateral.withdraw, borrow, repay,
+    //            liquidate, flashloan
+    //   memo: write
+    //   nft: create, mint, transfer, list, list.cancel, bid, bid.cancel, order.cancel (a listing
+    //        or a bid, when the instruction does not tell which), sale, escrow.deposit,
+    //        escrow.withdraw, pool.create, pool.close, pool.deposit, pool.withdraw
+    //   program: deploy, upgrade
+    //   record: write
+    //   stake: delegate, deactivate, split, merge, withdraw, pool.deposit, pool.withdraw
+    //   system: account.create, transfer
+    //   token: account.create, account.close, mint.create, mint, burn, transfer, approve, revoke
+    //   vault: deposit, withdraw
+    //   vote: cast
+    pub action: String,
     // Like what it means dude.
     pub timestamp: i64
 }
@@ -57,12 +84,241 @@ pub struct InstructionSet {
     pub properties: Vec<InstructionProperty>
 }
 
+/// Why an instruction did not make it into the decoded output.
+#[derive(Clone, Debug, Eq, Error, PartialEq, Serialize, Deserialize)]
+pub enum InstructionErrorReason {
+    /// No decoder is registered for the instruction's program.
+    #[error("Program is not supported")]
+    UnsupportedProgram,
+    /// The program's decoder did not recognise the instruction, see the logs for the details.
+    #[error("Instruction could not be decoded")]
+    Undecodable,
+    /// The decoder needs the transaction's compiled instructions, which were not passed along.
+    #[error("Instruction context is missing")]
+    MissingContext,
+    /// The decoder panicked while processing the instruction.
+    #[error("Decoder panicked")]
+    DecoderPanicked,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InstructionError {
+    // The local unique identifier of the instruction according to the transaction (not based on solana)
+    pub tx_instruction_id: i16,
+    // The transaction this instruction belongs to.
+    pub transaction_hash: String,
+    // If this is an inner instruction, we should depend on this
+    pub parent_index: i16,
+    // The program invoked by this instruction.
+    pub program: String,
+    // Why the instruction was not decoded.
+    pub reason: InstructionErrorReason,
+    pub timestamp: i64,
+}
+
+impl InstructionError {
+    fn new(instruction: &Instruction, reason: InstructionErrorReason) -> Self {
+        InstructionError {
+            tx_instruction_id: instruction.tx_instruction_id,
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index,
+            program: instruction.program.clone(),
+            reason,
+            timestamp: instruction.timestamp,
+        }
+    }
+}
+
+/// The decoded instructions, along with the instructions that could not be decoded.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct ProcessResult {
+    pub instruction_sets: Vec<InstructionSet>,
+    pub errors: Vec<InstructionError>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProgramDescription {
+    // The name of the decoder module interpreting this program.
+    pub name: String,
+    // The program addresses routed to this decoder.
+    pub program_addresses: Vec<String>,
+    // The function names this decoder may emit.
+    pub functions: Vec<String>,
+}
+
+impl ProgramDescription {
+    fn new(name: &str, program_addresses: &[&str], functions: &[&str]) -> Self {
+        ProgramDescription {
+            name: name.to_string(),
+            program_addresses: program_addresses.iter().map(|pa| pa.to_string()).collect(),
+            functions: functions.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+}
+
+/// Describes every decoder supported by `process`, i.e. which program addresses it handles and
+/// which function names it may emit.
+pub fn describe() -> Vec<ProgramDescription> {
+    vec![
+        ProgramDescription::new("native_associated_token_account",
+                                &[programs::native_associated_token_account::PROGRAM_ADDRESS],
+                                programs::native_associated_token_account::FUNCTIONS),
+        ProgramDescription::new("native_config",
+                                &[programs::native_config::PROGRAM_ADDRESS],
+                                programs::native_config::FUNCTIONS),
+        ProgramDescription::new("native_loader",
+                                &[programs::native_loader::PROGRAM_ADDRESS],
+                                programs::native_loader::FUNCTIONS),
+        ProgramDescription::new("bpf_loader",
+                                &[programs::bpf_loader::PROGRAM_ADDRESS,
+                                    programs::bpf_loader::PROGRAM_ADDRESS_2],
+                                programs::bpf_loader::FUNCTIONS),
+        ProgramDescription::new("bpf_loader_upgradeable",
+                                &[programs::bpf_loader_upgradeable::PROGRAM_ADDRESS],
+                                programs::bpf_loader_upgradeable::FUNCTIONS),
+        ProgramDescription::new("native_secp256k1",
+                                &[programs::native_secp256k1::PROGRAM_ADDRESS],
+                                programs::native_secp256k1::FUNCTIONS),
+        ProgramDescription::new("native_stake",
+                                &[programs::native_stake::PROGRAM_ADDRESS],
+                                programs::native_stake::FUNCTIONS),
+        ProgramDescription::new("native_system",
+                                &[programs::native_system::PROGRAM_ADDRESS],
+                                programs::native_system::FUNCTIONS),
+        ProgramDescription::new("native_token",
+                                &[programs::native_token::PROGRAM_ADDRESS],
+                                programs::native_token::FUNCTIONS),
+        ProgramDescription::new("native_token_lending",
+                                &[programs::native_token_lending::PROGRAM_ADDRESS],
+                                programs::native_token_lending::FUNCTIONS),
+        ProgramDescription::new("native_token_swap",
+                                &[programs::native_token_swap::PROGRAM_ADDRESS],
+                                programs::native_token_swap::FUNCTIONS),
+        ProgramDescription::new("serum_market",
+                                &[programs::serum_market::PROGRAM_ADDRESS_V1,
+                                    programs::serum_market::PROGRAM_ADDRESS_V2,
+                                    programs::serum_market::PROGRAM_ADDRESS_V3],
+                                programs::serum_market::FUNCTIONS),
+        ProgramDescription::new("native_vote",
+                                &[programs::native_vote::PROGRAM_ADDRESS],
+                                programs::native_vote::FUNCTIONS),
+        ProgramDescription::new("solend_token_lending",
+                                &[programs::solend_token_lending::PROGRAM_ADDRESS],
+                                programs::solend_token_lending::FUNCTIONS),
+        ProgramDescription::new("native_token_2022",
+                                &[programs::native_token_2022::PROGRAM_ADDRESS],
+                                programs::native_token_2022::FUNCTIONS),
+        ProgramDescription::new("native_compute_budget",
+                                &[programs::native_compute_budget::PROGRAM_ADDRESS],
+                                programs::native_compute_budget::FUNCTIONS),
+        ProgramDescription::new("native_ed25519",
+                                &[programs::native_ed25519::PROGRAM_ADDRESS],
+                                programs::native_ed25519::FUNCTIONS),
+        ProgramDescription::new("native_memo",
+                                &[programs::native_memo::PROGRAM_ADDRESS,
+                                    programs::native_memo::PROGRAM_ADDRESS_V1],
+                                programs::native_memo::FUNCTIONS),
+        ProgramDescription::new("native_stake_pool",
+                                &[programs::native_stake_pool::PROGRAM_ADDRESS],
+                                programs::native_stake_pool::FUNCTIONS),
+        ProgramDescription::new("native_governance",
+                                &[programs::native_governance::PROGRAM_ADDRESS],
+                                programs::native_governance::FUNCTIONS),
+        ProgramDescription::new("native_account_compression",
+                                &[programs::native_account_compression::PROGRAM_ADDRESS],
+                                programs::native_account_compression::FUNCTIONS),
+        ProgramDescription::new("native_noop",
+                                &[programs::native_noop::PROGRAM_ADDRESS],
+                                programs::native_noop::FUNCTIONS),
+        ProgramDescription::new("metaplex_token_metadata",
+                                &[programs::metaplex_token_metadata::PROGRAM_ADDRESS],
+                                programs::metaplex_token_metadata::FUNCTIONS),
+        ProgramDescription::new("metaplex_candy_guard",
+                                &[programs::metaplex_candy_guard::PROGRAM_ADDRESS],
+                                programs::metaplex_candy_guard::FUNCTIONS),
+        ProgramDescription::new("metaplex_candy_machine",
+                                &[programs::metaplex_candy_machine::PROGRAM_ADDRESS],
+                                programs::metaplex_candy_machine::FUNCTIONS),
+        ProgramDescription::new("metaplex_auction_house",
+                                &[programs::metaplex_auction_house::PROGRAM_ADDRESS],
+                                programs::metaplex_auction_house::FUNCTIONS),
+        ProgramDescription::new("metaplex_token_auth_rules",
+                                &[programs::metaplex_token_auth_rules::PROGRAM_ADDRESS],
+                                programs::metaplex_token_auth_rules::FUNCTIONS),
+        ProgramDescription::new("magic_eden_v2",
+                                &[programs::magic_eden_v2::PROGRAM_ADDRESS],
+                                programs::magic_eden_v2::FUNCTIONS),
+        ProgramDescription::new("tensorswap",
+                                &[programs::tensorswap::PROGRAM_ADDRESS],
+                                programs::tensorswap::FUNCTIONS),
+        ProgramDescription::new("raydium_amm",
+                                &[programs::raydium_amm::PROGRAM_ADDRESS],
+                                programs::raydium_amm::FUNCTIONS),
+        ProgramDescription::new("raydium_clmm",
+                                &[programs::raydium_clmm::PROGRAM_ADDRESS],
+                                programs::raydium_clmm::FUNCTIONS),
+        ProgramDescription::new("raydium_farm",
+                                &[programs::raydium_farm::PROGRAM_ADDRESS_V3,
+                                    programs::raydium_farm::PROGRAM_ADDRESS_V5],
+                                programs::raydium_farm::FUNCTIONS),
+        ProgramDescription::new("orca_token_swap",
+                                &[programs::orca_token_swap::PROGRAM_ADDRESS_V1,
+                                    programs::orca_token_swap::PROGRAM_ADDRESS_V2],
+                                programs::orca_token_swap::FUNCTIONS),
+        ProgramDescription::new("orca_whirlpool",
+                                &[programs::orca_whirlpool::PROGRAM_ADDRESS],
+                                programs::orca_whirlpool::FUNCTIONS),
+        ProgramDescription::new("orca_aquafarm",
+                                &[programs::orca_aquafarm::PROGRAM_ADDRESS],
+                                programs::orca_aquafarm::FUNCTIONS),
+        ProgramDescription::new("native_record",
+                                &[programs::native_record::PROGRAM_ADDRESS],
+                                programs::native_record::FUNCTIONS),
+        ProgramDescription::new("saber_stable_swap",
+                                &[programs::saber_stable_swap::PROGRAM_ADDRESS],
+                                programs::saber_stable_swap::FUNCTIONS),
+        ProgramDescription::new("quarry_mine",
+                                &[programs::quarry_mine::PROGRAM_ADDRESS],
+                                programs::quarry_mine::FUNCTIONS),
+        ProgramDescription::new("mercurial_stable_swap",
+                                &[programs::mercurial_stable_swap::PROGRAM_ADDRESS],
+                                programs::mercurial_stable_swap::FUNCTIONS),
+        ProgramDescription::new("lifinity_swap",
+                                &[programs::lifinity_swap::PROGRAM_ADDRESS],
+                                programs::lifinity_swap::FUNCTIONS),
+        ProgramDescription::new("crema_clmm",
+                                &[programs::crema_clmm::PROGRAM_ADDRESS],
+                                programs::crema_clmm::FUNCTIONS),
+        ProgramDescription::new("meteora_amm",
+                                &[programs::meteora_amm::PROGRAM_ADDRESS],
+                                programs::meteora_amm::FUNCTIONS),
+        ProgramDescription::new("meteora_vault",
+                                &[programs::meteora_vault::PROGRAM_ADDRESS],
+                                programs::meteora_vault::FUNCTIONS),
+    ]
+}
+
+/// Serializes `describe` into a json manifest so that downstream consumers can generate
+/// documentation and contracts from it.
+pub fn manifest() -> String {
+    serde_json::to_string(&describe()).unwrap_or_default()
+}
+
 /// Derive a simple, singular function that 'decompiles' support program instruction invocations
 /// into a database and json-compatible format based on Solana FM's instruction properties.
 pub async fn process(
     instructions: Vec<Instruction>,
     og_instructions: Option<Vec<CompiledInstruction>>
 ) -> Vec<InstructionSet> {
+    process_with_errors(instructions, og_instructions).await.instruction_sets
+}
+
+/// Same as `process`, but also reports every instruction that did not produce an instruction set
+/// and why, so that callers can route them elsewhere instead of dropping them silently.
+pub async fn process_with_errors(
+    instructions: Vec<Instruction>,
+    og_instructions: Option<Vec<CompiledInstruction>>
+) -> ProcessResult {
     let instruction_jobs: Vec<_> = instructions.into_iter()
         .map(|instruction| {
             let ogi = if let Some(res) = og_instructions.clone() {
@@ -70,101 +326,348 @@ pub async fn process(
             } else {
                 None
             };
-
-            spawn(async move {
-                match instruction.program.as_str() {
-                    programs::native_associated_token_account::PROGRAM_ADDRESS => {
-                        crate::programs::native_associated_token_account::fragment_instruction(
-                            instruction).await
-                    },
-                    programs::native_config::PROGRAM_ADDRESS => {
-                        crate::programs::native_config::fragment_instruction(instruction)
-                            .await
-                    },
-                    programs::native_loader::PROGRAM_ADDRESS => {
-                        crate::programs::native_loader::fragment_instruction(instruction)
-                            .await
-                    },
-                    programs::bpf_loader::PROGRAM_ADDRESS |
-                    programs::bpf_loader::PROGRAM_ADDRESS_2 => {
-                        crate::programs::bpf_loader::fragment_instruction(instruction)
-                            .await
-                    },
-                    programs::bpf_loader_upgradeable::PROGRAM_ADDRESS => {
-                        crate::programs::bpf_loader_upgradeable::fragment_instruction(instruction)
-                            .await
-                    }
-                    programs::native_secp256k1::PROGRAM_ADDRESS => {
-                        if let Some(og_instructs) = ogi {
-                            crate::programs::native_secp256k1::fragment_instruction(instruction,
-                                                                                    og_instructs.as_slice())
-                                .await
-                        } else {
-                            None
-                        }
-                    }
-                    programs::native_stake::PROGRAM_ADDRESS => {
-                        crate::programs::native_stake::fragment_instruction(instruction)
-                            .await
-                    }
-                    programs::native_system::PROGRAM_ADDRESS => {
-                        crate::programs::native_system::fragment_instruction(instruction)
-                            .await
-                    }
-                    programs::native_token::PROGRAM_ADDRESS => {
-                        crate::programs::native_token::fragment_instruction(instruction)
-                            .await
-                    }
-                    programs::native_token_lending::PROGRAM_ADDRESS => {
-                        crate::programs::native_token_lending::fragment_instruction(instruction)
-                            .await
-                    }
-                    programs::native_token_swap::PROGRAM_ADDRESS => {
-                        crate::programs::native_token_swap::fragment_instruction(instruction)
-                            .await
-                    }
-                    programs::serum_market::PROGRAM_ADDRESS_V1
-                        | programs::serum_market::PROGRAM_ADDRESS_V2
-                        | programs::serum_market::PROGRAM_ADDRESS_V3 => {
-                        crate::programs::serum_market::fragment_instruction(instruction)
-                            .await
-                    }
-                    programs::native_vote::PROGRAM_ADDRESS => {
-                        crate::programs::native_vote::fragment_instruction(instruction)
-                            .await
-                    }
-                    programs::solend_token_lending::PROGRAM_ADDRESS => {
-                        crate::programs::solend_token_lending::fragment_instruction(instruction)
-                            .await
-                    }
-                    _ => {
-                        info!("Looks like this program ({}) is an unsupported one.",
-                            instruction.program.to_string());
-
-                        None
-                    }
+            let reason = match instruction.program.as_str() {
+                programs::native_secp256k1::PROGRAM_ADDRESS if ogi.is_none() => {
+                    InstructionErrorReason::MissingContext
                 }
-            })
+                programs::native_ed25519::PROGRAM_ADDRESS
+                    if ogi.is_none()
+                        && programs::native_ed25519::refers_to_other_instructions(
+                            &instruction.data) => {
+                    InstructionErrorReason::MissingContext
+                }
+                _ => InstructionErrorReason::Undecodable,
+            };
+            let instruction_error = InstructionError::new(&instruction, reason);
+
+            (instruction_error, spawn(fragment_instruction(instruction, ogi)))
         })
         .collect();
 
-    let mut instruction_sets: Vec<InstructionSet> = Vec::new();
-    for job in instruction_jobs {
-        let res = job.await;
-        if let Ok(instruction_job_result) = res {
-            if let Some(instruction_set) = instruction_job_result {
-                instruction_sets.push(instruction_set);
+    let mut result = ProcessResult::default();
+    for (mut instruction_error, job) in instruction_jobs {
+        match job.await {
+            Ok(Ok(Some(instruction_set))) => result.instruction_sets.push(instruction_set),
+            Ok(Ok(None)) => result.errors.push(instruction_error),
+            Ok(Err(reason)) => {
+                instruction_error.reason = reason;
+                result.errors.push(instruction_error);
+            }
+            Err(_) => {
+                instruction_error.reason = InstructionErrorReason::DecoderPanicked;
+                result.errors.push(instruction_error);
             }
         }
     }
 
-    instruction_sets
+    result
+}
+
+/// Hands an instruction to the decoder of its program. Fails with `UnsupportedProgram` when no
+/// decoder is registered for it, `Ok(None)` means the decoder could not interpret it.
+async fn fragment_instruction(
+    instruction: Instruction,
+    ogi: Option<Vec<CompiledInstruction>>
+) -> Result<Option<InstructionSet>, InstructionErrorReason> {
+    Ok(match instruction.program.as_str() {
+        programs::native_associated_token_account::PROGRAM_ADDRESS => {
+            crate::programs::native_associated_token_account::fragment_instruction(
+                instruction).await
+        },
+        programs::native_config::PROGRAM_ADDRESS => {
+            crate::programs::native_config::fragment_instruction(instruction)
+                .await
+        },
+        programs::native_loader::PROGRAM_ADDRESS => {
+            crate::programs::native_loader::fragment_instruction(instruction)
+                .await
+        },
+        programs::bpf_loader::PROGRAM_ADDRESS |
+        programs::bpf_loader::PROGRAM_ADDRESS_2 => {
+            crate::programs::bpf_loader::fragment_instruction(instruction)
+                .await
+        },
+        programs::bpf_loader_upgradeable::PROGRAM_ADDRESS => {
+            crate::programs::bpf_loader_upgradeable::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_secp256k1::PROGRAM_ADDRESS => {
+            if let Some(og_instructs) = ogi {
+                crate::programs::native_secp256k1::fragment_instruction(instruction,
+                                                                        og_instructs.as_slice())
+                    .await
+            } else {
+                None
+            }
+        }
+        programs::native_ed25519::PROGRAM_ADDRESS => {
+            crate::programs::native_ed25519::fragment_instruction(instruction,
+                                                                  ogi.as_deref())
+                .await
+        }
+        programs::native_stake::PROGRAM_ADDRESS => {
+            crate::programs::native_stake::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_system::PROGRAM_ADDRESS => {
+            crate::programs::native_system::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_token::PROGRAM_ADDRESS => {
+            crate::programs::native_token::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_token_lending::PROGRAM_ADDRESS => {
+            crate::programs::native_token_lending::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_token_swap::PROGRAM_ADDRESS => {
+            crate::programs::native_token_swap::fragment_instruction(instruction)
+                .await
+        }
+        programs::serum_market::PROGRAM_ADDRESS_V1
+            | programs::serum_market::PROGRAM_ADDRESS_V2
+            | programs::serum_market::PROGRAM_ADDRESS_V3 => {
+            crate::programs::serum_market::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_vote::PROGRAM_ADDRESS => {
+            crate::programs::native_vote::fragment_instruction(instruction)
+                .await
+        }
+        programs::solend_token_lending::PROGRAM_ADDRESS => {
+            crate::programs::solend_token_lending::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_token_2022::PROGRAM_ADDRESS => {
+            crate::programs::native_token_2022::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_compute_budget::PROGRAM_ADDRESS => {
+            crate::programs::native_compute_budget::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_memo::PROGRAM_ADDRESS
+        | programs::native_memo::PROGRAM_ADDRESS_V1 => {
+            crate::programs::native_memo::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_stake_pool::PROGRAM_ADDRESS => {
+            crate::programs::native_stake_pool::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_governance::PROGRAM_ADDRESS => {
+            crate::programs::native_governance::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_account_compression::PROGRAM_ADDRESS => {
+            crate::programs::native_account_compression::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_noop::PROGRAM_ADDRESS => {
+            crate::programs::native_noop::fragment_instruction(instruction)
+                .await
+        }
+        programs::metaplex_token_metadata::PROGRAM_ADDRESS => {
+            crate::programs::metaplex_token_metadata::fragment_instruction(instruction)
+                .await
+        }
+        programs::metaplex_candy_guard::PROGRAM_ADDRESS => {
+            crate::programs::metaplex_candy_guard::fragment_instruction(instruction)
+                .await
+        }
+        programs::metaplex_candy_machine::PROGRAM_ADDRESS => {
+            crate::programs::metaplex_candy_machine::fragment_instruction(instruction)
+                .await
+        }
+        programs::metaplex_auction_house::PROGRAM_ADDRESS => {
+            crate::programs::metaplex_auction_house::fragment_instruction(instruction)
+                .await
+        }
+        programs::metaplex_token_auth_rules::PROGRAM_ADDRESS => {
+            crate::programs::metaplex_token_auth_rules::fragment_instruction(instruction)
+                .await
+        }
+        programs::magic_eden_v2::PROGRAM_ADDRESS => {
+            crate::programs::magic_eden_v2::fragment_instruction(instruction)
+                .await
+        }
+        programs::tensorswap::PROGRAM_ADDRESS => {
+            crate::programs::tensorswap::fragment_instruction(instruction)
+                .await
+        }
+        programs::raydium_amm::PROGRAM_ADDRESS => {
+            crate::programs::raydium_amm::fragment_instruction(instruction)
+                .await
+        }
+        programs::raydium_clmm::PROGRAM_ADDRESS => {
+            crate::programs::raydium_clmm::fragment_instruction(instruction)
+                .await
+        }
+        programs::raydium_farm::PROGRAM_ADDRESS_V3
+        | programs::raydium_farm::PROGRAM_ADDRESS_V5 => {
+            crate::programs::raydium_farm::fragment_instruction(instruction)
+                .await
+        }
+        programs::orca_token_swap::PROGRAM_ADDRESS_V1
+        | programs::orca_token_swap::PROGRAM_ADDRESS_V2 => {
+            crate::programs::orca_token_swap::fragment_instruction(instruction)
+                .await
+        }
+        programs::orca_whirlpool::PROGRAM_ADDRESS => {
+            crate::programs::orca_whirlpool::fragment_instruction(instruction)
+                .await
+        }
+        programs::orca_aquafarm::PROGRAM_ADDRESS => {
+            crate::programs::orca_aquafarm::fragment_instruction(instruction)
+                .await
+        }
+        programs::native_record::PROGRAM_ADDRESS => {
+            crate::programs::native_record::fragment_instruction(instruction)
+                .await
+        }
+        programs::saber_stable_swap::PROGRAM_ADDRESS => {
+            crate::programs::saber_stable_swap::fragment_instruction(instruction)
+                .await
+        }
+        programs::quarry_mine::PROGRAM_ADDRESS => {
+            crate::programs::quarry_mine::fragment_instruction(instruction)
+                .await
+        }
+        programs::mercurial_stable_swap::PROGRAM_ADDRESS => {
+            crate::programs::mercurial_stable_swap::fragment_instruction(instruction)
+                .await
+        }
+        programs::lifinity_swap::PROGRAM_ADDRESS => {
+            crate::programs::lifinity_swap::fragment_instruction(instruction)
+                .await
+        }
+        programs::crema_clmm::PROGRAM_ADDRESS => {
+            crate::programs::crema_clmm::fragment_instruction(instruction)
+                .await
+        }
+        programs::meteora_amm::PROGRAM_ADDRESS => {
+            crate::programs::meteora_amm::fragment_instruction(instruction)
+                .await
+        }
+        programs::meteora_vault::PROGRAM_ADDRESS => {
+            crate::programs::meteora_vault::fragment_instruction(instruction)
+                .await
+        }
+        _ => {
+            info!("Looks like this program ({}) is an unsupported one.",
+                instruction.program.to_string());
+
+            return Err(InstructionErrorReason::UnsupportedProgram)
+        }
+    })
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    fn instruction(tx_instruction_id: i16, program: &str, data: Vec<u8>) -> Instruction {
+        Instruction {
+            tx_instruction_id,
+            transaction_hash: "determinism".to_string(),
+            program: program.to_string(),
+            data,
+            parent_index: -1,
+            timestamp: 1_634_000_000,
+        }
+    }
+
+    // A batch touching several decoders, along with every kind of error.
+    fn batch() -> Vec<Instruction> {
+        let mut system_transfer = vec![2, 0, 0, 0];
+        system_transfer.extend_from_slice(&1_000_000u64.to_le_bytes());
+        let mut token_transfer = vec![3];
+        token_transfer.extend_from_slice(&42u64.to_le_bytes());
+        // One signature whose public key, signature and message all follow the offsets.
+        let mut ed25519_self_contained = vec![1, 0];
+        for field in &[16u16, u16::MAX, 80, u16::MAX, 112, 2, u16::MAX] {
+            ed25519_self_contained.extend_from_slice(&field.to_le_bytes());
+        }
+        ed25519_self_contained.extend_from_slice(&[7; 98]);
+        // The same signature, with its message in the transaction's first instruction.
+        let mut ed25519_other_message = ed25519_self_contained.clone();
+        ed25519_other_message[14..16].copy_from_slice(&0u16.to_le_bytes());
+
+        vec![
+            instruction(0, programs::native_system::PROGRAM_ADDRESS, system_transfer),
+            instruction(1, programs::native_memo::PROGRAM_ADDRESS, b"gm".to_vec()),
+            instruction(2, programs::native_token::PROGRAM_ADDRESS, token_transfer),
+            instruction(3, programs::native_token::PROGRAM_ADDRESS, vec![255]),
+            instruction(4, programs::native_secp256k1::PROGRAM_ADDRESS, vec![0]),
+            instruction(5, "Unsupported1111111111111111111111111111111", vec![1, 2, 3]),
+            instruction(6, programs::native_ed25519::PROGRAM_ADDRESS, ed25519_self_contained),
+            instruction(7, programs::native_ed25519::PROGRAM_ADDRESS, ed25519_other_message),
+        ]
+    }
+
+    fn run(runtime: &tokio::runtime::Runtime) -> (String, String) {
+        let instruction_sets = runtime.block_on(process(batch(), None));
+        let result = runtime.block_on(process_with_errors(batch(), None));
+
+        (
+            serde_json::to_string(&instruction_sets).unwrap(),
+            serde_json::to_string(&result).unwrap(),
+        )
+    }
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn test_described_programs_are_dispatched() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        for description in describe() {
+            for program_address in description.program_addresses {
+                // Whatever the decoder makes of the data, it must be the one handed the
+                // instruction. A panic still means it was reached.
+                let empty = instruction(0, &program_address, vec![]);
+                let result = runtime.block_on(runtime.spawn(fragment_instruction(empty, None)));
+                assert!(
+                    !matches!(result, Ok(Err(InstructionErrorReason::UnsupportedProgram))),
+                    "{} ({}) is described but not dispatched",
+                    program_address,
+                    description.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_process_is_deterministic() {
+        let current_thread = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let multi_thread = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(4)
+            .build()
+            .unwrap();
+
+        let expected = run(&current_thread);
+        assert_eq!(run(&current_thread), expected);
+        assert_eq!(run(&multi_thread), expected);
+        assert_eq!(run(&multi_thread), expected);
+
+        let result: ProcessResult = serde_json::from_str(&expected.1).unwrap();
+        assert_eq!(result.instruction_sets.len(), 4);
+        assert_eq!(
+            result.errors.iter().map(|error| error.reason.clone()).collect::<Vec<_>>(),
+            vec![
+                InstructionErrorReason::Undecodable,
+                InstructionErrorReason::MissingContext,
+                InstructionErrorReason::UnsupportedProgram,
+                InstructionErrorReason::MissingContext,
+            ]
+        );
+    }
 }
\ No newline at end of file
@@ -0,0 +1,50 @@
+//! Events used to emit information necessary to index changes made to a concurrent merkle tree.
+//! They are Borsh serialized into the data of a noop program instruction.
+
+use borsh::BorshDeserialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::programs::account_compression::instruction::Node;
+
+#[derive(BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum AccountCompressionEvent {
+    ChangeLog(ChangeLogEvent),
+    ApplicationData(ApplicationDataEvent),
+}
+
+#[derive(BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum ChangeLogEvent {
+    V1(ChangeLogEventV1),
+}
+
+#[derive(BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ChangeLogEventV1 {
+    /// Public key of the ConcurrentMerkleTree
+    pub id: Pubkey,
+
+    /// Nodes of off-chain merkle tree needed by indexer
+    pub path: Vec<PathNode>,
+
+    /// Index corresponding to the number of successful operations on this tree.
+    /// Used by the off-chain indexer to figure out when there are gaps to be backfilled.
+    pub seq: u64,
+
+    /// Bitmap of node parity (used when hashing)
+    pub index: u32,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct PathNode {
+    pub node: Node,
+    pub index: u32,
+}
+
+#[derive(BorshDeserialize, Clone, Debug, PartialEq)]
+pub enum ApplicationDataEvent {
+    V1(ApplicationDataEventV1),
+}
+
+#[derive(BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct ApplicationDataEventV1 {
+    pub application_data: Vec<u8>,
+}
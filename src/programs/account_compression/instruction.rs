@@ -0,0 +1,110 @@
+//! Instruction types
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+
+pub const INIT_EMPTY_MERKLE_TREE: Discriminator = [191, 11, 119, 7, 180, 107, 220, 110];
+pub const REPLACE_LEAF: Discriminator = [204, 165, 76, 100, 73, 147, 0, 128];
+pub const TRANSFER_AUTHORITY: Discriminator = [48, 169, 76, 72, 229, 180, 55, 161];
+pub const VERIFY_LEAF: Discriminator = [124, 220, 22, 223, 104, 10, 250, 224];
+pub const APPEND: Discriminator = [149, 120, 18, 222, 236, 225, 88, 203];
+pub const INSERT_OR_APPEND: Discriminator = [6, 42, 50, 190, 51, 109, 178, 168];
+pub const CLOSE_EMPTY_TREE: Discriminator = [50, 14, 219, 107, 78, 103, 16, 103];
+
+/// A 32 byte node of the merkle tree.
+pub type Node = [u8; 32];
+
+/// Instructions supported by the account compression program.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AccountCompressionInstruction {
+    /// Creates a new merkle tree with maximum leaf capacity of `power(2, max_depth)`
+    /// and a minimum concurrency limit of `max_buffer_size`.
+    InitEmptyMerkleTree {
+        max_depth: u32,
+        max_buffer_size: u32,
+    },
+
+    /// Executes an instruction that overwrites a leaf node.
+    /// Composing programs should check that the data hashed into previous_leaf
+    /// matches the authority information necessary to execute this instruction.
+    ReplaceLeaf {
+        root: Node,
+        previous_leaf: Node,
+        new_leaf: Node,
+        index: u32,
+    },
+
+    /// Transfers `authority`.
+    /// Requires `authority` to sign
+    TransferAuthority {
+        new_authority: Pubkey,
+    },
+
+    /// Verifies a provided proof and leaf.
+    /// If invalid, throws an error.
+    VerifyLeaf {
+        root: Node,
+        leaf: Node,
+        index: u32,
+    },
+
+    /// This instruction allows the tree's `authority` to append a new leaf to the tree
+    /// without having to supply a proof.
+    Append {
+        leaf: Node,
+    },
+
+    /// This instruction takes a proof, and will attempt to write the given leaf
+    /// to the specified index in the tree. If the insert operation fails, the leaf will be `append`-ed
+    /// to the tree.
+    InsertOrAppend {
+        root: Node,
+        leaf: Node,
+        index: u32,
+    },
+
+    /// Closes an empty tree, returning its lamports to the recipient.
+    CloseEmptyTree,
+}
+
+impl AccountCompressionInstruction {
+    /// Unpacks a byte buffer into an AccountCompressionInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            INIT_EMPTY_MERKLE_TREE => {
+                let (max_depth, max_buffer_size) = unpack_args(rest)?;
+                Self::InitEmptyMerkleTree {
+                    max_depth,
+                    max_buffer_size,
+                }
+            }
+            REPLACE_LEAF => {
+                let (root, previous_leaf, new_leaf, index) = unpack_args(rest)?;
+                Self::ReplaceLeaf {
+                    root,
+                    previous_leaf,
+                    new_leaf,
+                    index,
+                }
+            }
+            TRANSFER_AUTHORITY => Self::TransferAuthority {
+                new_authority: unpack_args(rest)?,
+            },
+            VERIFY_LEAF => {
+                let (root, leaf, index) = unpack_args(rest)?;
+                Self::VerifyLeaf { root, leaf, index }
+            }
+            APPEND => Self::Append {
+                leaf: unpack_args(rest)?,
+            },
+            INSERT_OR_APPEND => {
+                let (root, leaf, index) = unpack_args(rest)?;
+                Self::InsertOrAppend { root, leaf, index }
+            }
+            CLOSE_EMPTY_TREE => Self::CloseEmptyTree,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
@@ -0,0 +1,5 @@
+//! Vendored spl-account-compression definitions, trimmed to what is needed to interpret its
+//! instructions and the events it logs through the noop program.
+
+pub mod events;
+pub mod instruction;
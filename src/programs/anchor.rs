@@ -0,0 +1,28 @@
+//! Helpers shared by the decoders of programs built with Anchor, which prefixes the Borsh encoded
+//! arguments of every instruction with an 8 byte discriminator: the first 8 bytes of
+//! sha256("global:<instruction_name>").
+
+use borsh::BorshDeserialize;
+use solana_program::program_error::ProgramError;
+
+pub const DISCRIMINATOR_BYTES: usize = 8;
+
+pub type Discriminator = [u8; DISCRIMINATOR_BYTES];
+
+/// Splits the discriminator off the instruction data.
+pub fn unpack_discriminator(input: &[u8]) -> Result<(Discriminator, &[u8]), ProgramError> {
+    if input.len() < DISCRIMINATOR_BYTES {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let (discriminator, rest) = input.split_at(DISCRIMINATOR_BYTES);
+    let mut value = [0u8; DISCRIMINATOR_BYTES];
+    value.copy_from_slice(discriminator);
+
+    Ok((value, rest))
+}
+
+/// Deserializes the arguments following the discriminator. Like Anchor, trailing bytes are
+/// ignored.
+pub fn unpack_args<T: BorshDeserialize>(input: &[u8]) -> Result<T, ProgramError> {
+    T::deserialize(&mut &input[..]).map_err(|_| ProgramError::InvalidInstructionData)
+}
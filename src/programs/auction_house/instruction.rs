@@ -0,0 +1,185 @@
+//! Instruction types
+
+use solana_program::program_error::ProgramError;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+
+pub const SELL: Discriminator = [51, 230, 133, 164, 1, 127, 131, 173];
+pub const BUY: Discriminator = [102, 6, 61, 18, 1, 218, 235, 234];
+pub const PUBLIC_BUY: Discriminator = [169, 84, 218, 35, 42, 206, 16, 171];
+pub const EXECUTE_SALE: Discriminator = [37, 74, 217, 157, 79, 49, 35, 6];
+pub const EXECUTE_PARTIAL_SALE: Discriminator = [163, 18, 35, 157, 49, 164, 203, 133];
+pub const DEPOSIT: Discriminator = [242, 35, 198, 137, 82, 225, 242, 182];
+pub const WITHDRAW: Discriminator = [183, 18, 70, 156, 148, 109, 161, 34];
+pub const CANCEL: Discriminator = [232, 219, 223, 41, 219, 236, 220, 190];
+
+/// Instructions supported by the Auction House program.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuctionHouseInstruction {
+    /// Create a sell bid by creating a `seller_trade_state` account and approving the program as
+    /// the token delegate.
+    Sell {
+        trade_state_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    },
+
+    /// Create a private buy bid by creating a `buyer_trade_state` account and an `escrow_payment`
+    /// account and funding the escrow with the necessary SOL or SPL token amount.
+    Buy {
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    },
+
+    /// Create a public buy bid, which is not tied to a specific token account.
+    PublicBuy {
+        trade_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    },
+
+    /// Execute sale between provided buyer and seller trade state accounts transferring funds to
+    /// seller wallet and token to buyer wallet.
+    ExecuteSale {
+        escrow_payment_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+    },
+
+    /// Execute a sale for part of the tokens listed.
+    ExecutePartialSale {
+        escrow_payment_bump: u8,
+        free_trade_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        partial_order_size: Option<u64>,
+        partial_order_price: Option<u64>,
+    },
+
+    /// Deposit `amount` into the escrow payment account for your specific wallet.
+    Deposit {
+        escrow_payment_bump: u8,
+        amount: u64,
+    },
+
+    /// Withdraw `amount` from the escrow payment account for your specific wallet.
+    Withdraw {
+        escrow_payment_bump: u8,
+        amount: u64,
+    },
+
+    /// Cancel a bid or ask by revoking the token delegate, transferring all lamports from the
+    /// trade state account to the fee payer, and setting the trade state account data to zero.
+    Cancel { buyer_price: u64, token_size: u64 },
+}
+
+impl AuctionHouseInstruction {
+    /// Unpacks a byte buffer into an AuctionHouseInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            SELL => {
+                let (
+                    trade_state_bump,
+                    free_trade_state_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                ) = unpack_args(rest)?;
+                Self::Sell {
+                    trade_state_bump,
+                    free_trade_state_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                }
+            }
+            BUY => {
+                let (trade_state_bump, escrow_payment_bump, buyer_price, token_size) =
+                    unpack_args(rest)?;
+                Self::Buy {
+                    trade_state_bump,
+                    escrow_payment_bump,
+                    buyer_price,
+                    token_size,
+                }
+            }
+            PUBLIC_BUY => {
+                let (trade_state_bump, escrow_payment_bump, buyer_price, token_size) =
+                    unpack_args(rest)?;
+                Self::PublicBuy {
+                    trade_state_bump,
+                    escrow_payment_bump,
+                    buyer_price,
+                    token_size,
+                }
+            }
+            EXECUTE_SALE => {
+                let (
+                    escrow_payment_bump,
+                    free_trade_state_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                ) = unpack_args(rest)?;
+                Self::ExecuteSale {
+                    escrow_payment_bump,
+                    free_trade_state_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                }
+            }
+            EXECUTE_PARTIAL_SALE => {
+                let (
+                    escrow_payment_bump,
+                    free_trade_state_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                    partial_order_size,
+                    partial_order_price,
+                ) = unpack_args(rest)?;
+                Self::ExecutePartialSale {
+                    escrow_payment_bump,
+                    free_trade_state_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                    partial_order_size,
+                    partial_order_price,
+                }
+            }
+            DEPOSIT => {
+                let (escrow_payment_bump, amount) = unpack_args(rest)?;
+                Self::Deposit {
+                    escrow_payment_bump,
+                    amount,
+                }
+            }
+            WITHDRAW => {
+                let (escrow_payment_bump, amount) = unpack_args(rest)?;
+                Self::Withdraw {
+                    escrow_payment_bump,
+                    amount,
+                }
+            }
+            CANCEL => {
+                let (buyer_price, token_size) = unpack_args(rest)?;
+                Self::Cancel {
+                    buyer_price,
+                    token_size,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
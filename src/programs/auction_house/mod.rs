@@ -0,0 +1,4 @@
+//! Vendored mpl-auction-house definitions, trimmed to what is needed to interpret its
+//! instructions.
+
+pub mod instruction;
@@ -7,6 +7,12 @@ use crate::{InstructionProperty, Instruction, InstructionSet, InstructionFunctio
 pub const PROGRAM_ADDRESS: &str = "BPFLoader1111111111111111111111111111111111";
 pub const PROGRAM_ADDRESS_2: &str = "BPFLoader2111111111111111111111111111111111";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "write",
+    "finalize",
+];
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -30,6 +36,7 @@ pub async fn fragment_instruction(
                             parent_index: _instruction.parent_index.clone(),
                             program: _instruction.program.clone(),
                             function_name: "write".to_string(),
+                            action: "".to_string(),
                             timestamp: _instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -62,6 +69,7 @@ pub async fn fragment_instruction(
                             parent_index: _instruction.parent_index.clone(),
                             program: _instruction.program.clone(),
                             function_name: "finalize".to_string(),
+                            action: "".to_string(),
                             timestamp: _instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -0,0 +1,126 @@
+//! Instruction types
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+
+pub const INITIALIZE: Discriminator = [175, 175, 109, 31, 13, 152, 155, 237];
+pub const MINT: Discriminator = [51, 57, 225, 47, 182, 146, 137, 166];
+pub const MINT_V2: Discriminator = [120, 121, 23, 146, 173, 110, 199, 205];
+pub const ROUTE: Discriminator = [229, 23, 203, 151, 122, 227, 173, 42];
+pub const SET_AUTHORITY: Discriminator = [133, 250, 37, 21, 110, 163, 26, 121];
+pub const UNWRAP: Discriminator = [126, 175, 198, 14, 212, 69, 50, 44];
+pub const UPDATE: Discriminator = [219, 200, 88, 176, 158, 63, 253, 127];
+pub const WITHDRAW: Discriminator = [183, 18, 70, 156, 148, 109, 161, 34];
+pub const WRAP: Discriminator = [178, 40, 10, 189, 228, 129, 186, 140];
+
+/// The guards, in the order of their feature flags.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum GuardType {
+    BotTax,
+    SolPayment,
+    TokenPayment,
+    StartDate,
+    ThirdPartySigner,
+    TokenGate,
+    Gatekeeper,
+    EndDate,
+    AllowList,
+    MintLimit,
+    NftPayment,
+    RedeemedAmount,
+    AddressGate,
+    NftGate,
+    NftBurn,
+    TokenBurn,
+    FreezeSolPayment,
+    FreezeTokenPayment,
+    ProgramGate,
+    Allocation,
+    Token2022Payment,
+}
+
+/// Arguments for a route transaction.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct RouteArgs {
+    /// The target guard type.
+    pub guard: GuardType,
+    /// Arguments for the guard instruction.
+    pub data: Vec<u8>,
+}
+
+/// Instructions supported by the Candy Guard program.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CandyGuardInstruction {
+    /// Create a new candy guard account, `data` being the serialized guard sets.
+    Initialize { data: Vec<u8> },
+
+    /// Mint an NFT from a candy machine wrapped in the candy guard.
+    Mint {
+        mint_args: Vec<u8>,
+        label: Option<String>,
+    },
+
+    /// Mint an NFT of any token standard from a candy machine wrapped in the candy guard.
+    MintV2 {
+        mint_args: Vec<u8>,
+        label: Option<String>,
+    },
+
+    /// Route the transaction to a guard instruction.
+    Route {
+        args: RouteArgs,
+        label: Option<String>,
+    },
+
+    /// Set a new authority of the candy guard.
+    SetAuthority { new_authority: Pubkey },
+
+    /// Remove a candy guard from a candy machine, setting the authority to the candy guard
+    /// authority.
+    Unwrap,
+
+    /// Update the guard sets of the candy guard.
+    Update { data: Vec<u8> },
+
+    /// Withdraw the rent lamports and send them to the authority address.
+    Withdraw,
+
+    /// Add a candy guard to a candy machine.
+    Wrap,
+}
+
+impl CandyGuardInstruction {
+    /// Unpacks a byte buffer into a CandyGuardInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            INITIALIZE => Self::Initialize {
+                data: unpack_args(rest)?,
+            },
+            MINT => {
+                let (mint_args, label) = unpack_args(rest)?;
+                Self::Mint { mint_args, label }
+            }
+            MINT_V2 => {
+                let (mint_args, label) = unpack_args(rest)?;
+                Self::MintV2 { mint_args, label }
+            }
+            ROUTE => {
+                let (args, label) = unpack_args(rest)?;
+                Self::Route { args, label }
+            }
+            SET_AUTHORITY => Self::SetAuthority {
+                new_authority: unpack_args(rest)?,
+            },
+            UNWRAP => Self::Unwrap,
+            UPDATE => Self::Update {
+                data: unpack_args(rest)?,
+            },
+            WITHDRAW => Self::Withdraw,
+            WRAP => Self::Wrap,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
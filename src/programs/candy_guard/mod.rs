@@ -0,0 +1,4 @@
+//! Vendored mpl-candy-guard definitions, trimmed to what is needed to interpret its
+//! instructions.
+
+pub mod instruction;
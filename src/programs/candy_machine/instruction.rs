@@ -0,0 +1,174 @@
+//! Instruction types
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+use crate::programs::token_metadata::state::TokenStandard;
+
+pub const INITIALIZE: Discriminator = [175, 175, 109, 31, 13, 152, 155, 237];
+pub const INITIALIZE_V2: Discriminator = [67, 153, 175, 39, 218, 16, 38, 32];
+pub const ADD_CONFIG_LINES: Discriminator = [223, 50, 224, 227, 151, 8, 115, 106];
+pub const UPDATE: Discriminator = [219, 200, 88, 176, 158, 63, 253, 127];
+pub const SET_AUTHORITY: Discriminator = [133, 250, 37, 21, 110, 163, 26, 121];
+pub const SET_MINT_AUTHORITY: Discriminator = [67, 127, 155, 187, 100, 174, 103, 121];
+pub const SET_COLLECTION: Discriminator = [192, 254, 206, 76, 168, 182, 59, 223];
+pub const SET_COLLECTION_V2: Discriminator = [229, 35, 61, 91, 15, 14, 99, 160];
+pub const SET_TOKEN_STANDARD: Discriminator = [147, 212, 106, 195, 30, 170, 209, 128];
+pub const MINT: Discriminator = [51, 57, 225, 47, 182, 146, 137, 166];
+pub const MINT_V2: Discriminator = [120, 121, 23, 146, 173, 110, 199, 205];
+pub const WITHDRAW: Discriminator = [183, 18, 70, 156, 148, 109, 161, 34];
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Creator {
+    /// Pubkey address
+    pub address: Pubkey,
+    /// Whether the creator is verified or not
+    pub verified: bool,
+    // Share of secondary sales royalty
+    pub percentage_share: u8,
+}
+
+/// Candy machine configuration data.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct CandyMachineData {
+    /// Number of assets available
+    pub items_available: u64,
+    /// Symbol for the asset
+    pub symbol: String,
+    /// Secondary sales royalty basis points (0-10000)
+    pub seller_fee_basis_points: u16,
+    /// Max supply of each individual asset (default 0)
+    pub max_supply: u64,
+    /// Indicates if the asset is mutable or not (default yes)
+    pub is_mutable: bool,
+    /// List of creators
+    pub creators: Vec<Creator>,
+    /// Config line settings
+    pub config_line_settings: Option<ConfigLineSettings>,
+    /// Hidden setttings
+    pub hidden_settings: Option<HiddenSettings>,
+}
+
+/// Hidden settings for large mints used with off-chain data.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct HiddenSettings {
+    /// Asset prefix name
+    pub name: String,
+    /// Shared URI
+    pub uri: String,
+    /// Hash of the hidden settings file
+    pub hash: [u8; 32],
+}
+
+/// Config line settings to allocate space for individual name + URI.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct ConfigLineSettings {
+    /// Common name prefix
+    pub prefix_name: String,
+    /// Length of the remaining part of the name
+    pub name_length: u32,
+    /// Common URI prefix
+    pub prefix_uri: String,
+    /// Length of the remaining part of the URI
+    pub uri_length: u32,
+    /// Indicates whether to use a senquential index generator or not
+    pub is_sequential: bool,
+}
+
+/// Config line struct for storing asset (NFT) data pre-mint.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct ConfigLine {
+    /// Name of the asset.
+    pub name: String,
+    /// URI to JSON metadata.
+    pub uri: String,
+}
+
+/// Instructions supported by the Candy Machine Core program.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CandyMachineInstruction {
+    /// Initialize the candy machine account with the specified data.
+    Initialize { data: CandyMachineData },
+
+    /// Initialize the candy machine account with the specified data and token standard.
+    InitializeV2 {
+        data: CandyMachineData,
+        token_standard: TokenStandard,
+    },
+
+    /// Add the configuration (name + uri) of each NFT to the account data.
+    AddConfigLines {
+        index: u32,
+        config_lines: Vec<ConfigLine>,
+    },
+
+    /// Update the candy machine configuration.
+    Update { data: CandyMachineData },
+
+    /// Set a new authority of the candy machine.
+    SetAuthority { new_authority: Pubkey },
+
+    /// Set a new mint authority of the candy machine.
+    SetMintAuthority,
+
+    /// Set the collection mint for the candy machine.
+    SetCollection,
+
+    /// Set the collection mint for the candy machine, supporting programmable NFTs.
+    SetCollectionV2,
+
+    /// Set the token standard of the minted NFTs.
+    SetTokenStandard { token_standard: TokenStandard },
+
+    /// Mint an NFT. Only the candy machine mint authority is allowed to mint.
+    Mint,
+
+    /// Mint an NFT of any token standard.
+    MintV2,
+
+    /// Withdraw the rent lamports and send them to the authority address.
+    Withdraw,
+}
+
+impl CandyMachineInstruction {
+    /// Unpacks a byte buffer into a CandyMachineInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            INITIALIZE => Self::Initialize {
+                data: unpack_args(rest)?,
+            },
+            INITIALIZE_V2 => {
+                let (data, token_standard) = unpack_args(rest)?;
+                Self::InitializeV2 {
+                    data,
+                    token_standard,
+                }
+            }
+            ADD_CONFIG_LINES => {
+                let (index, config_lines) = unpack_args(rest)?;
+                Self::AddConfigLines {
+                    index,
+                    config_lines,
+                }
+            }
+            UPDATE => Self::Update {
+                data: unpack_args(rest)?,
+            },
+            SET_AUTHORITY => Self::SetAuthority {
+                new_authority: unpack_args(rest)?,
+            },
+            SET_MINT_AUTHORITY => Self::SetMintAuthority,
+            SET_COLLECTION => Self::SetCollection,
+            SET_COLLECTION_V2 => Self::SetCollectionV2,
+            SET_TOKEN_STANDARD => Self::SetTokenStandard {
+                token_standard: unpack_args(rest)?,
+            },
+            MINT => Self::Mint,
+            MINT_V2 => Self::MintV2,
+            WITHDRAW => Self::Withdraw,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
@@ -0,0 +1,4 @@
+//! Vendored mpl-candy-machine-core definitions, trimmed to what is needed to interpret its
+//! instructions.
+
+pub mod instruction;
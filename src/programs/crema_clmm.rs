@@ -0,0 +1,414 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "CLMM9tUoggJu2wagPkkqs9eFG4BWhVBZWkP1qv3Sp7tR";
+
+pub const SWAP: Discriminator = [248, 198, 158, 145, 225, 117, 135, 200];
+pub const OPEN_POSITION: Discriminator = [135, 128, 47, 77, 15, 152, 240, 49];
+pub const CLOSE_POSITION: Discriminator = [123, 134, 81, 0, 49, 68, 98, 98];
+pub const INCREASE_LIQUIDITY: Discriminator = [46, 156, 243, 118, 13, 205, 251, 178];
+pub const INCREASE_LIQUIDITY_WITH_FIXED_TOKEN: Discriminator =
+    [174, 15, 121, 101, 108, 2, 174, 159];
+pub const DECREASE_LIQUIDITY: Discriminator = [160, 38, 208, 111, 104, 91, 44, 1];
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "swap",
+    "open-position",
+    "close-position",
+    "increase-liquidity",
+    "increase-liquidity-with-fixed-token",
+    "decrease-liquidity",
+];
+
+/// Instructions of the Crema concentrated liquidity program this decoder interprets.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CremaInstruction {
+    /// Swap one pool token for the other, `amount` is the input when `by_amount_in` and the
+    /// output otherwise.
+    Swap {
+        a_to_b: bool,
+        by_amount_in: bool,
+        amount: u64,
+        amount_limit: u64,
+        sqrt_price_limit: u128,
+    },
+    /// Open a position between two ticks.
+    OpenPosition {
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    },
+    /// Close an empty position.
+    ClosePosition,
+    /// Add liquidity to a position.
+    IncreaseLiquidity {
+        delta_liquidity: u128,
+        amount_a_max: u64,
+        amount_b_max: u64,
+    },
+    /// Add liquidity to a position, fixing the amount of one of the tokens.
+    IncreaseLiquidityWithFixedToken {
+        amount_a: u64,
+        amount_b: u64,
+        is_a_fixed: bool,
+    },
+    /// Remove liquidity from a position.
+    DecreaseLiquidity {
+        delta_liquidity: u128,
+        amount_a_min: u64,
+        amount_b_min: u64,
+    },
+}
+
+impl CremaInstruction {
+    /// Unpacks a byte buffer into a CremaInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            SWAP => {
+                let (a_to_b, by_amount_in, amount, amount_limit, sqrt_price_limit) =
+                    unpack_args(rest)?;
+                Self::Swap {
+                    a_to_b,
+                    by_amount_in,
+                    amount,
+                    amount_limit,
+                    sqrt_price_limit,
+                }
+            }
+            OPEN_POSITION => {
+                let (tick_lower_index, tick_upper_index) = unpack_args(rest)?;
+                Self::OpenPosition {
+                    tick_lower_index,
+                    tick_upper_index,
+                }
+            }
+            CLOSE_POSITION => Self::ClosePosition,
+            INCREASE_LIQUIDITY => {
+                let (delta_liquidity, amount_a_max, amount_b_max) = unpack_args(rest)?;
+                Self::IncreaseLiquidity {
+                    delta_liquidity,
+                    amount_a_max,
+                    amount_b_max,
+                }
+            }
+            INCREASE_LIQUIDITY_WITH_FIXED_TOKEN => {
+                let (amount_a, amount_b, is_a_fixed) = unpack_args(rest)?;
+                Self::IncreaseLiquidityWithFixedToken {
+                    amount_a,
+                    amount_b,
+                    is_a_fixed,
+                }
+            }
+            DECREASE_LIQUIDITY => {
+                let (delta_liquidity, amount_a_min, amount_b_min) = unpack_args(rest)?;
+                Self::DecreaseLiquidity {
+                    delta_liquidity,
+                    amount_a_min,
+                    amount_b_min,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = CremaInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let crema_instruction = ui.clone();
+            match crema_instruction {
+                CremaInstruction::Swap {
+                    a_to_b,
+                    by_amount_in,
+                    amount,
+                    amount_limit,
+                    sqrt_price_limit,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "swap".to_string(),
+                            action: "dex.swap".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "a_to_b".to_string(),
+                                value: (a_to_b as i32).to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "by_amount_in".to_string(),
+                                value: (by_amount_in as i32).to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_limit".to_string(),
+                                value: amount_limit.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "sqrt_price_limit".to_string(),
+                                value: sqrt_price_limit.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CremaInstruction::OpenPosition { tick_lower_index, tick_upper_index } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "open-position".to_string(),
+                            action: "dex.position.open".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "tick_lower_index".to_string(),
+                                value: tick_lower_index.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "tick_upper_index".to_string(),
+                                value: tick_upper_index.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CremaInstruction::ClosePosition => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "close-position".to_string(),
+                            action: "dex.position.close".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                CremaInstruction::IncreaseLiquidity {
+                    delta_liquidity,
+                    amount_a_max,
+                    amount_b_max,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "increase-liquidity".to_string(),
+                            action: "dex.liquidity.add".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "delta_liquidity".to_string(),
+                                value: delta_liquidity.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_a_max".to_string(),
+                                value: amount_a_max.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_b_max".to_string(),
+                                value: amount_b_max.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CremaInstruction::IncreaseLiquidityWithFixedToken {
+                    amount_a,
+                    amount_b,
+                    is_a_fixed,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "increase-liquidity-with-fixed-token".to_string(),
+                            action: "dex.liquidity.add".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_a".to_string(),
+                                value: amount_a.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_b".to_string(),
+                                value: amount_b.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "is_a_fixed".to_string(),
+                                value: (is_a_fixed as i32).to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CremaInstruction::DecreaseLiquidity {
+                    delta_liquidity,
+                    amount_a_min,
+                    amount_b_min,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "decrease-liquidity".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "delta_liquidity".to_string(),
+                                value: delta_liquidity.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_a_min".to_string(),
+                                value: amount_a_min.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_b_min".to_string(),
+                                value: amount_b_min.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/crema_clmm] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
@@ -0,0 +1,471 @@
+//! Program instructions
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+use solana_program::pubkey::Pubkey;
+
+/// The type of the vote threshold used to resolve a vote on a Proposal
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteThreshold {
+    /// Voting threshold of Yes votes in % required to tip the vote (Approval Quorum)
+    YesVotePercentage(u8),
+    /// Voting threshold which guarantees a minimum number of votes are cast (Quorum)
+    QuorumPercentage(u8),
+    /// Disabled vote threshold indicates the given voting population cannot vote
+    Disabled,
+}
+
+/// The type of vote tipping to use on a Proposal
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteTipping {
+    /// Tip when there is no way for another option to win and the vote threshold has been reached
+    Strict,
+    /// Tip when an option reaches the vote threshold and has more vote weight than any other option
+    Early,
+    /// Never tip the vote early, always wait until the voting period ends
+    Disabled,
+}
+
+/// Governance config
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+pub struct GovernanceConfig {
+    /// The type of the vote threshold used for community vote
+    pub community_vote_threshold: VoteThreshold,
+    /// Minimum community weight a governance token owner must possess to be able to create a
+    /// proposal
+    pub min_community_weight_to_create_proposal: u64,
+    /// The wait time in seconds before transactions can be executed after proposal is successfully
+    /// voted on
+    pub min_transaction_hold_up_time: u32,
+    /// The base voting time in seconds for proposal to be open for voting
+    pub voting_base_time: u32,
+    /// Conditions under which a Community vote will complete early
+    pub community_vote_tipping: VoteTipping,
+    /// The type of the vote threshold used for council vote
+    pub council_vote_threshold: VoteThreshold,
+    /// The threshold for Council Veto votes
+    pub council_veto_vote_threshold: VoteThreshold,
+    /// Minimum council weight a governance token owner must possess to be able to create a
+    /// proposal
+    pub min_council_weight_to_create_proposal: u64,
+    /// Conditions under which a Council vote will complete early
+    pub council_vote_tipping: VoteTipping,
+    /// The threshold for Community Veto votes
+    pub community_veto_vote_threshold: VoteThreshold,
+    /// Voting cool of time
+    pub voting_cool_off_time: u32,
+    /// The number of active proposals exempt from the Proposal security deposit
+    pub deposit_exempt_proposal_count: u8,
+}
+
+/// The source of max vote weight used for voting
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MintMaxVoterWeightSource {
+    /// Fraction (10^10 precision) of the governing mint supply is used as max vote weight
+    SupplyFraction(u64),
+    /// Absolute value, irrelevant of the actual mint supply, is used as max voter weight
+    Absolute(u64),
+}
+
+/// The type of the governing token defines
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GoverningTokenType {
+    /// Liquid token is a token which is fully liquid and the token owner retains full authority
+    Liquid,
+    /// Membership token is a token controlled by Realm authority
+    Membership,
+    /// Dormant token is a token which is only a placeholder
+    Dormant,
+}
+
+/// Realm Config instruction args
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+pub struct GoverningTokenConfigArgs {
+    /// Indicates whether an external addin program should be used to provide voters weights
+    pub use_voter_weight_addin: bool,
+    /// Indicates whether an external addin program should be used to provide max voters weight
+    pub use_max_voter_weight_addin: bool,
+    /// Governing token type defines how the token is used for governance
+    pub token_type: GoverningTokenType,
+}
+
+/// Realm Config instruction args
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+pub struct RealmConfigArgs {
+    /// Indicates whether council_mint should be used
+    pub use_council_mint: bool,
+    /// Min number of community tokens required to create a governance
+    pub min_community_weight_to_create_governance: u64,
+    /// The source used for community mint max vote weight source
+    pub community_mint_max_voter_weight_source: MintMaxVoterWeightSource,
+    /// Community token config args
+    pub community_token_config_args: GoverningTokenConfigArgs,
+    /// Council token config args
+    pub council_token_config_args: GoverningTokenConfigArgs,
+}
+
+/// Type of MultiChoice
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiChoiceType {
+    /// Multiple options can be approved with full weight allocated to each approved option
+    FullWeight,
+    /// Multiple options can be approved with weight allocated proportionally
+    Weighted,
+}
+
+/// Proposal Vote type
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteType {
+    /// Single choice vote with mutually exclusive choices
+    SingleChoice,
+    /// Multiple options can be selected with up to max_voter_options per voter
+    MultiChoice {
+        /// Type of MultiChoice
+        choice_type: MultiChoiceType,
+        /// The min number of options a voter must choose
+        min_voter_options: u8,
+        /// The max number of options a voter can choose
+        max_voter_options: u8,
+        /// The max number of wining options
+        max_winning_options: u8,
+    },
+}
+
+/// Voter choice for a proposal option
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+pub struct VoteChoice {
+    /// The rank given to the choice by voter
+    pub rank: u8,
+    /// The voter's weight percentage given by the voter to the choice
+    pub weight_percentage: u8,
+}
+
+/// User's vote
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Vote {
+    /// Vote approving choices
+    Approve(Vec<VoteChoice>),
+    /// Vote rejecting proposal
+    Deny,
+    /// Declare indifference to proposal
+    Abstain,
+    /// Veto proposal
+    Veto,
+}
+
+/// Account metadata used to define Instructions
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct AccountMetaData {
+    /// An account's public key
+    pub pubkey: Pubkey,
+    /// True if an Instruction requires a Transaction signature matching `pubkey`.
+    pub is_signer: bool,
+    /// True if the `pubkey` can be loaded as a read-write account.
+    pub is_writable: bool,
+}
+
+/// Custom instruction data which is stored in a proposal transaction
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct InstructionData {
+    /// Pubkey of the instruction processor that executes this instruction
+    pub program_id: Pubkey,
+    /// Metadata for what accounts should be passed to the instruction processor
+    pub accounts: Vec<AccountMetaData>,
+    /// Opaque data passed to the instruction processor
+    pub data: Vec<u8>,
+}
+
+/// SetRealmAuthority instruction action
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum SetRealmAuthorityAction {
+    /// Sets realm authority without any checks
+    SetUnchecked,
+    /// Sets realm authority and checks the new new authority is one of the realm's governances
+    SetChecked,
+    /// Removes realm authority
+    Remove,
+}
+
+/// Instructions supported by the Governance program
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum GovernanceInstruction {
+    /// Creates Governance Realm account which aggregates governances for given Community Mint and
+    /// optional Council Mint
+    CreateRealm {
+        /// UTF-8 encoded Governance Realm name
+        name: String,
+
+        /// Realm config args
+        config_args: RealmConfigArgs,
+    },
+
+    /// Deposits governing tokens (Community or Council) to Governance Realm and establishes your
+    /// voter weight to be used for voting within the Realm
+    DepositGoverningTokens {
+        /// The amount to deposit into the realm
+        amount: u64,
+    },
+
+    /// Withdraws governing tokens (Community or Council) from Governance Realm and downgrades your
+    /// voter weight within the Realm.
+    WithdrawGoverningTokens {},
+
+    /// Sets Governance Delegate for the given Realm and Governing Token Mint (Community or Council)
+    SetGovernanceDelegate {
+        /// New Governance Delegate
+        new_governance_delegate: Option<Pubkey>,
+    },
+
+    /// Creates Governance account which can be used to govern any arbitrary Solana account or asset
+    CreateGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+    },
+
+    /// Legacy CreateProgramGovernance instruction
+    CreateProgramGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+
+        /// Indicate whether Program's upgrade_authority should be transferred to the Governance
+        /// PDA
+        transfer_upgrade_authority: bool,
+    },
+
+    /// Creates Proposal account for Transactions which will be executed at some point in the future
+    CreateProposal {
+        /// UTF-8 encoded name of the proposal
+        name: String,
+
+        /// Link to a gist explaining the proposal
+        description_link: String,
+
+        /// Proposal vote type
+        vote_type: VoteType,
+
+        /// Proposal options
+        options: Vec<String>,
+
+        /// Indicates whether the proposal has the deny option
+        use_deny_option: bool,
+
+        /// Unique seed for the Proposal PDA
+        proposal_seed: Pubkey,
+    },
+
+    /// Adds a signatory to the Proposal which means this Proposal can't leave Draft state until
+    /// yet another Signatory signs
+    AddSignatory {
+        /// Signatory to add to the Proposal
+        signatory: Pubkey,
+    },
+
+    /// Formerly RemoveSignatory. Exists for backwards-compatibility.
+    Legacy1,
+
+    /// Inserts Transaction with a set of instructions for the Proposal at the given index position
+    InsertTransaction {
+        /// The index of the option the transaction is for
+        option_index: u8,
+        /// Transaction index to be inserted at.
+        index: u16,
+        /// Waiting time (in seconds) between vote period ending and this being eligible for
+        /// execution
+        hold_up_time: u32,
+
+        /// Instructions Data
+        instructions: Vec<InstructionData>,
+    },
+
+    /// Removes Transaction from the Proposal
+    RemoveTransaction,
+
+    /// Cancels Proposal by changing its state to Canceled
+    CancelProposal,
+
+    /// Signs off Proposal indicating the Signatory approves the Proposal
+    SignOffProposal,
+
+    ///  Uses your voter weight (deposited Community or Council tokens) to cast a vote on a Proposal
+    CastVote {
+        /// User's vote
+        vote: Vote,
+    },
+
+    /// Finalizes vote in case the Vote was not automatically tipped within max_voting_time period
+    FinalizeVote {},
+
+    ///  Relinquish Vote removes voter weight from a Proposal and removes it from voter's active
+    /// votes
+    RelinquishVote {},
+
+    /// Executes a Transaction in the Proposal
+    ExecuteTransaction,
+
+    /// Creates Mint Governance account which governs a mint
+    CreateMintGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+
+        /// Indicate whether Mint's authorities (MintTokens, FreezeAccount) should be transferred
+        /// to the Governance PDA
+        transfer_mint_authorities: bool,
+    },
+
+    /// Creates Token Governance account which governs a token account
+    CreateTokenGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+
+        /// Indicate whether the token account authorities (AccountOwner and CloseAccount) should
+        /// be transferred to the Governance PDA
+        transfer_account_authorities: bool,
+    },
+
+    /// Sets GovernanceConfig for a Governance
+    SetGovernanceConfig {
+        /// New governance config
+        config: GovernanceConfig,
+    },
+
+    /// Legacy FlagTransactionError instruction
+    FlagTransactionError,
+
+    /// Sets new Realm authority
+    SetRealmAuthority {
+        /// Set action ( SetUnchecked, SetChecked, Remove)
+        action: SetRealmAuthorityAction,
+    },
+
+    /// Sets realm config
+    SetRealmConfig {
+        /// Realm config args
+        config_args: RealmConfigArgs,
+    },
+
+    /// Creates TokenOwnerRecord with 0 deposit amount
+    CreateTokenOwnerRecord {},
+
+    /// Updates ProgramMetadata account
+    UpdateProgramMetadata {},
+
+    /// Creates native SOL treasury account for a Governance account
+    CreateNativeTreasury,
+
+    /// Revokes (burns) membership governing tokens for the given TokenOwnerRecord and hence takes
+    /// away governance power from the TokenOwner
+    RevokeGoverningTokens {
+        /// The amount to revoke
+        amount: u64,
+    },
+
+    /// Refunds ProposalDeposit once the given proposal is no longer active
+    RefundProposalDeposit {},
+
+    /// Transitions an off-chain or manually executable Proposal from Succeeded into Completed state
+    CompleteProposal {},
+
+    /// Adds a required signatory to the Governance, which will be applied to all proposals created
+    /// with it
+    AddRequiredSignatory {
+        /// Required signatory to add to the Governance
+        signatory: Pubkey,
+    },
+
+    /// Removes a required signatory from the Governance
+    RemoveRequiredSignatory,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unpack_create_proposal() {
+        let proposal_seed = Pubkey::new(&[1u8; 32]);
+        let mut data = vec![6];
+        data.extend_from_slice(&[4, 0, 0, 0]);
+        data.extend_from_slice(b"Fund");
+        data.extend_from_slice(&[0, 0, 0, 0]);
+        data.push(0);
+        data.extend_from_slice(&[1, 0, 0, 0, 3, 0, 0, 0]);
+        data.extend_from_slice(b"Yes");
+        data.push(1);
+        data.extend_from_slice(proposal_seed.as_ref());
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&data).unwrap(),
+            GovernanceInstruction::CreateProposal {
+                name: "Fund".to_string(),
+                description_link: "".to_string(),
+                vote_type: VoteType::SingleChoice,
+                options: vec!["Yes".to_string()],
+                use_deny_option: true,
+                proposal_seed,
+            }
+        );
+
+        // Without the seed this is a v2.2 payload, which this layout does not accept.
+        assert!(GovernanceInstruction::try_from_slice(&data[..data.len() - 32]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_cast_vote() {
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&[13, 0, 1, 0, 0, 0, 0, 100]).unwrap(),
+            GovernanceInstruction::CastVote {
+                vote: Vote::Approve(vec![VoteChoice {
+                    rank: 0,
+                    weight_percentage: 100,
+                }]),
+            }
+        );
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&[13, 3]).unwrap(),
+            GovernanceInstruction::CastVote { vote: Vote::Veto }
+        );
+    }
+
+    #[test]
+    fn test_unpack_set_governance_config() {
+        let mut data = vec![19];
+        data.extend_from_slice(&[0, 60]);
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&259_200u32.to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&[1, 10]);
+        data.push(2);
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.push(1);
+        data.push(2);
+        data.extend_from_slice(&3_600u32.to_le_bytes());
+        data.push(10);
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&data).unwrap(),
+            GovernanceInstruction::SetGovernanceConfig {
+                config: GovernanceConfig {
+                    community_vote_threshold: VoteThreshold::YesVotePercentage(60),
+                    min_community_weight_to_create_proposal: 1,
+                    min_transaction_hold_up_time: 0,
+                    voting_base_time: 259_200,
+                    community_vote_tipping: VoteTipping::Strict,
+                    council_vote_threshold: VoteThreshold::QuorumPercentage(10),
+                    council_veto_vote_threshold: VoteThreshold::Disabled,
+                    min_council_weight_to_create_proposal: 1,
+                    council_vote_tipping: VoteTipping::Early,
+                    community_veto_vote_threshold: VoteThreshold::Disabled,
+                    voting_cool_off_time: 3_600,
+                    deposit_exempt_proposal_count: 10,
+                },
+            }
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! Program instructions of spl-governance v2.2, for payloads which predate the v3 layout
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+use solana_program::pubkey::Pubkey;
+
+use crate::programs::governance::instruction::{
+    InstructionData, MintMaxVoterWeightSource, SetRealmAuthorityAction, Vote, VoteTipping,
+    VoteType,
+};
+
+/// The type of the vote threshold percentage used to resolve a vote on a Proposal
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteThresholdPercentage {
+    /// Voting threshold of Yes votes in % required to tip the vote
+    YesVote(u8),
+    /// The minimum number of votes in % out of the entire pool of governance tokens eligible to
+    /// vote which must be cast for the vote to be valid
+    Quorum(u8),
+}
+
+/// Governance config
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+pub struct GovernanceConfig {
+    /// The type of the vote threshold used for voting
+    pub vote_threshold_percentage: VoteThresholdPercentage,
+    /// Minimum community weight a governance token owner must possess to be able to create a
+    /// proposal
+    pub min_community_weight_to_create_proposal: u64,
+    /// Minimum waiting time in seconds for a transaction to be executed after proposal is voted on
+    pub min_transaction_hold_up_time: u32,
+    /// Time limit in seconds for proposal to be open for voting
+    pub max_voting_time: u32,
+    /// Conditions under which a vote will complete early
+    pub vote_tipping: VoteTipping,
+    /// The time period in seconds within which a Proposal can be still cancelled after being
+    /// created
+    pub proposal_cool_off_time: u32,
+    /// Minimum council weight a governance token owner must possess to be able to create a
+    /// proposal
+    pub min_council_weight_to_create_proposal: u64,
+}
+
+/// Realm Config instruction args
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize, Serialize)]
+pub struct RealmConfigArgs {
+    /// Indicates whether council_mint should be used
+    pub use_council_mint: bool,
+    /// Min number of community tokens required to create a governance
+    pub min_community_weight_to_create_governance: u64,
+    /// The source used for community mint max vote weight source
+    pub community_mint_max_vote_weight_source: MintMaxVoterWeightSource,
+    /// Indicates whether an external addin program should be used to provide community voters
+    /// weights
+    pub use_community_voter_weight_addin: bool,
+    /// Indicates whether an external addin program should be used to provide max voters weight
+    /// for the community mint
+    pub use_max_community_voter_weight_addin: bool,
+}
+
+/// Instructions supported by the Governance program
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[allow(clippy::large_enum_variant)]
+pub enum GovernanceInstruction {
+    /// Creates Governance Realm account which aggregates governances for given Community Mint and
+    /// optional Council Mint
+    CreateRealm {
+        /// UTF-8 encoded Governance Realm name
+        name: String,
+
+        /// Realm config args
+        config_args: RealmConfigArgs,
+    },
+
+    /// Deposits governing tokens (Community or Council) to Governance Realm and establishes your
+    /// voter weight to be used for voting within the Realm
+    DepositGoverningTokens {
+        /// The amount to deposit into the realm
+        amount: u64,
+    },
+
+    /// Withdraws governing tokens (Community or Council) from Governance Realm and downgrades your
+    /// voter weight within the Realm.
+    WithdrawGoverningTokens {},
+
+    /// Sets Governance Delegate for the given Realm and Governing Token Mint (Community or Council)
+    SetGovernanceDelegate {
+        /// New Governance Delegate
+        new_governance_delegate: Option<Pubkey>,
+    },
+
+    /// Creates Governance account which can be used to govern any arbitrary Solana account or asset
+    CreateGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+    },
+
+    /// Creates Program Governance account which governs an upgradable program
+    CreateProgramGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+
+        /// Indicate whether Program's upgrade_authority should be transferred to the Governance
+        /// PDA
+        transfer_upgrade_authority: bool,
+    },
+
+    /// Creates Proposal account for Transactions which will be executed at some point in the future
+    CreateProposal {
+        /// UTF-8 encoded name of the proposal
+        name: String,
+
+        /// Link to a gist explaining the proposal
+        description_link: String,
+
+        /// Proposal vote type
+        vote_type: VoteType,
+
+        /// Proposal options
+        options: Vec<String>,
+
+        /// Indicates whether the proposal has the deny option
+        use_deny_option: bool,
+    },
+
+    /// Adds a signatory to the Proposal which means this Proposal can't leave Draft state until
+    /// yet another Signatory signs
+    AddSignatory {
+        /// Signatory to add to the Proposal
+        signatory: Pubkey,
+    },
+
+    /// Removes a Signatory from the Proposal
+    RemoveSignatory {
+        /// Signatory to remove from the Proposal
+        signatory: Pubkey,
+    },
+
+    /// Inserts Transaction with a set of instructions for the Proposal at the given index position
+    InsertTransaction {
+        /// The index of the option the transaction is for
+        option_index: u8,
+        /// Transaction index to be inserted at.
+        index: u16,
+        /// Waiting time (in seconds) between vote period ending and this being eligible for
+        /// execution
+        hold_up_time: u32,
+
+        /// Instructions Data
+        instructions: Vec<InstructionData>,
+    },
+
+    /// Removes Transaction from the Proposal
+    RemoveTransaction,
+
+    /// Cancels Proposal by changing its state to Canceled
+    CancelProposal,
+
+    /// Signs off Proposal indicating the Signatory approves the Proposal
+    SignOffProposal,
+
+    ///  Uses your voter weight (deposited Community or Council tokens) to cast a vote on a Proposal
+    CastVote {
+        /// User's vote
+        vote: Vote,
+    },
+
+    /// Finalizes vote in case the Vote was not automatically tipped within max_voting_time period
+    FinalizeVote {},
+
+    ///  Relinquish Vote removes voter weight from a Proposal and removes it from voter's active
+    /// votes
+    RelinquishVote,
+
+    /// Executes a Transaction in the Proposal
+    ExecuteTransaction,
+
+    /// Creates Mint Governance account which governs a mint
+    CreateMintGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+
+        /// Indicate whether Mint's authorities (MintTokens, FreezeAccount) should be transferred
+        /// to the Governance PDA
+        transfer_mint_authorities: bool,
+    },
+
+    /// Creates Token Governance account which governs a token account
+    CreateTokenGovernance {
+        /// Governance config
+        config: GovernanceConfig,
+
+        /// Indicate whether the token account authorities (AccountOwner and CloseAccount) should
+        /// be transferred to the Governance PDA
+        transfer_account_authorities: bool,
+    },
+
+    /// Sets GovernanceConfig for a Governance
+    SetGovernanceConfig {
+        /// New governance config
+        config: GovernanceConfig,
+    },
+
+    /// Flags a transaction and its parent Proposal with error status
+    FlagTransactionError,
+
+    /// Sets new Realm authority
+    SetRealmAuthority {
+        /// Set action ( SetUnchecked, SetChecked, Remove)
+        action: SetRealmAuthorityAction,
+    },
+
+    /// Sets realm config
+    SetRealmConfig {
+        /// Realm config args
+        config_args: RealmConfigArgs,
+    },
+
+    /// Creates TokenOwnerRecord with 0 deposit amount
+    CreateTokenOwnerRecord {},
+
+    /// Updates ProgramMetadata account
+    UpdateProgramMetadata {},
+
+    /// Creates native SOL treasury account for a Governance account
+    CreateNativeTreasury,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::programs::governance::instruction::GovernanceInstruction as GovernanceInstructionV3;
+
+    #[test]
+    fn test_unpack_create_proposal() {
+        let mut data = vec![6];
+        data.extend_from_slice(&[4, 0, 0, 0]);
+        data.extend_from_slice(b"Fund");
+        data.extend_from_slice(&[0, 0, 0, 0]);
+        data.push(0);
+        data.extend_from_slice(&[1, 0, 0, 0, 3, 0, 0, 0]);
+        data.extend_from_slice(b"Yes");
+        data.push(1);
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&data).unwrap(),
+            GovernanceInstruction::CreateProposal {
+                name: "Fund".to_string(),
+                description_link: "".to_string(),
+                vote_type: VoteType::SingleChoice,
+                options: vec!["Yes".to_string()],
+                use_deny_option: true,
+            }
+        );
+        assert!(GovernanceInstructionV3::try_from_slice(&data).is_err());
+    }
+
+    #[test]
+    fn test_unpack_remove_signatory() {
+        let signatory = Pubkey::new(&[2u8; 32]);
+        let mut data = vec![8];
+        data.extend_from_slice(signatory.as_ref());
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&data).unwrap(),
+            GovernanceInstruction::RemoveSignatory { signatory }
+        );
+        assert!(GovernanceInstructionV3::try_from_slice(&data).is_err());
+    }
+
+    #[test]
+    fn test_unpack_create_governance() {
+        let mut data = vec![4];
+        data.extend_from_slice(&[0, 60]);
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&259_200u32.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&data).unwrap(),
+            GovernanceInstruction::CreateGovernance {
+                config: GovernanceConfig {
+                    vote_threshold_percentage: VoteThresholdPercentage::YesVote(60),
+                    min_community_weight_to_create_proposal: 1,
+                    min_transaction_hold_up_time: 0,
+                    max_voting_time: 259_200,
+                    vote_tipping: VoteTipping::Early,
+                    proposal_cool_off_time: 0,
+                    min_council_weight_to_create_proposal: 1,
+                },
+            }
+        );
+        assert!(GovernanceInstructionV3::try_from_slice(&data).is_err());
+    }
+
+    #[test]
+    fn test_unpack_create_realm() {
+        let mut data = vec![0];
+        data.extend_from_slice(&[3, 0, 0, 0]);
+        data.extend_from_slice(b"DAO");
+        data.push(1);
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&10_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+        assert_eq!(
+            GovernanceInstruction::try_from_slice(&data).unwrap(),
+            GovernanceInstruction::CreateRealm {
+                name: "DAO".to_string(),
+                config_args: RealmConfigArgs {
+                    use_council_mint: true,
+                    min_community_weight_to_create_governance: 1,
+                    community_mint_max_vote_weight_source:
+                        MintMaxVoterWeightSource::SupplyFraction(10_000_000_000),
+                    use_community_voter_weight_addin: false,
+                    use_max_community_voter_weight_addin: false,
+                },
+            }
+        );
+        assert!(GovernanceInstructionV3::try_from_slice(&data).is_err());
+    }
+}
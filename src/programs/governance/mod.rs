@@ -0,0 +1,7 @@
+//! Vendored spl-governance definitions, trimmed to what is needed to interpret its instructions.
+//!
+//! `instruction` mirrors spl-governance v3.1. Payloads written against v2.2 (proposals without a
+//! seed, the older governance and realm configs) do not fit it and are read with `instruction_v2`.
+
+pub mod instruction;
+pub mod instruction_v2;
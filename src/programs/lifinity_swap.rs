@@ -0,0 +1,246 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "EewxydAPCCVuNEyrVN68PuSYdQ7wKn27V9Gjeoi8dy3S";
+
+pub const SWAP: Discriminator = [248, 198, 158, 145, 225, 117, 135, 200];
+pub const DEPOSIT_ALL_TOKEN_TYPES: Discriminator = [32, 95, 69, 60, 75, 79, 205, 238];
+pub const WITHDRAW_ALL_TOKEN_TYPES: Discriminator = [189, 254, 156, 174, 210, 9, 164, 216];
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "swap",
+    "deposit-all-token-types",
+    "withdraw-all-token-types",
+];
+
+/// Instructions of the Lifinity swap program this decoder interprets.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LifinityInstruction {
+    /// Swap one pool token for the other, priced from the pool's oracle.
+    Swap {
+        amount_in: u64,
+        minimum_amount_out: u64,
+    },
+    /// Deposit both pool tokens in exchange for pool tokens.
+    DepositAllTokenTypes {
+        pool_token_amount: u64,
+        maximum_token_a_amount: u64,
+        maximum_token_b_amount: u64,
+    },
+    /// Burn pool tokens in exchange for both pool tokens.
+    WithdrawAllTokenTypes {
+        pool_token_amount: u64,
+        minimum_token_a_amount: u64,
+        minimum_token_b_amount: u64,
+    },
+}
+
+impl LifinityInstruction {
+    /// Unpacks a byte buffer into a LifinityInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            SWAP => {
+                let (amount_in, minimum_amount_out) = unpack_args(rest)?;
+                Self::Swap {
+                    amount_in,
+                    minimum_amount_out,
+                }
+            }
+            DEPOSIT_ALL_TOKEN_TYPES => {
+                let (pool_token_amount, maximum_token_a_amount, maximum_token_b_amount) =
+                    unpack_args(rest)?;
+                Self::DepositAllTokenTypes {
+                    pool_token_amount,
+                    maximum_token_a_amount,
+                    maximum_token_b_amount,
+                }
+            }
+            WITHDRAW_ALL_TOKEN_TYPES => {
+                let (pool_token_amount, minimum_token_a_amount, minimum_token_b_amount) =
+                    unpack_args(rest)?;
+                Self::WithdrawAllTokenTypes {
+                    pool_token_amount,
+                    minimum_token_a_amount,
+                    minimum_token_b_amount,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = LifinityInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let lifinity_instruction = ui.clone();
+            match lifinity_instruction {
+                LifinityInstruction::Swap { amount_in, minimum_amount_out } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "swap".to_string(),
+                            action: "dex.swap".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_in".to_string(),
+                                value: amount_in.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_amount_out".to_string(),
+                                value: minimum_amount_out.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                LifinityInstruction::DepositAllTokenTypes {
+                    pool_token_amount,
+                    maximum_token_a_amount,
+                    maximum_token_b_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit-all-token-types".to_string(),
+                            action: "dex.liquidity.add".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "pool_token_amount".to_string(),
+                                value: pool_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "maximum_token_a_amount".to_string(),
+                                value: maximum_token_a_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "maximum_token_b_amount".to_string(),
+                                value: maximum_token_b_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                LifinityInstruction::WithdrawAllTokenTypes {
+                    pool_token_amount,
+                    minimum_token_a_amount,
+                    minimum_token_b_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-all-token-types".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "pool_token_amount".to_string(),
+                                value: pool_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_token_a_amount".to_string(),
+                                value: minimum_token_a_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_token_b_amount".to_string(),
+                                value: minimum_token_b_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/lifinity_swap] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
@@ -0,0 +1,590 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "M2mx93ekt1fmXSVkTrUL9xVFHkmME8HTUi5Cyc5aF7K";
+
+pub const BUY: Discriminator = [102, 6, 61, 18, 1, 218, 235, 234];
+pub const BUY_V2: Discriminator = [184, 23, 238, 97, 103, 197, 211, 61];
+pub const SELL: Discriminator = [51, 230, 133, 164, 1, 127, 131, 173];
+pub const CANCEL_BUY: Discriminator = [238, 76, 36, 218, 132, 177, 224, 233];
+pub const CANCEL_SELL: Discriminator = [198, 198, 130, 203, 163, 95, 175, 75];
+pub const EXECUTE_SALE_V2: Discriminator = [91, 220, 49, 223, 204, 129, 53, 193];
+pub const DEPOSIT: Discriminator = [242, 35, 198, 137, 82, 225, 242, 182];
+pub const WITHDRAW: Discriminator = [183, 18, 70, 156, 148, 109, 161, 34];
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "buy",
+    "buy-v2",
+    "sell",
+    "cancel-buy",
+    "cancel-sell",
+    "execute-sale-v2",
+    "deposit",
+    "withdraw",
+];
+
+/// Instructions of the Magic Eden v2 program this decoder interprets. The program is not open
+/// source, the argument layouts follow its published IDL.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MagicEdenInstruction {
+    /// Place a bid.
+    Buy {
+        buyer_state_bump: u8,
+        escrow_payment_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        buyer_state_expiry: i64,
+    },
+    /// Place a bid, with the royalty the buyer is willing to pay.
+    BuyV2 {
+        buyer_price: u64,
+        token_size: u64,
+        buyer_state_expiry: i64,
+        buyer_creator_royalty_bp: u16,
+    },
+    /// List a token.
+    Sell {
+        seller_state_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        seller_state_expiry: i64,
+    },
+    /// Cancel a bid.
+    CancelBuy {
+        buyer_price: u64,
+        token_size: u64,
+        buyer_state_expiry: i64,
+    },
+    /// Cancel a listing.
+    CancelSell {
+        buyer_price: u64,
+        token_size: u64,
+        seller_state_expiry: i64,
+    },
+    /// Match a bid and a listing.
+    ExecuteSaleV2 {
+        escrow_payment_bump: u8,
+        program_as_signer_bump: u8,
+        buyer_price: u64,
+        token_size: u64,
+        buyer_state_expiry: i64,
+        seller_state_expiry: i64,
+        maker_fee_bp: i16,
+        taker_fee_bp: u16,
+    },
+    /// Deposit into the buyer escrow.
+    Deposit { escrow_payment_bump: u8, amount: u64 },
+    /// Withdraw from the buyer escrow.
+    Withdraw { escrow_payment_bump: u8, amount: u64 },
+}
+
+impl MagicEdenInstruction {
+    /// Unpacks a byte buffer into a MagicEdenInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            BUY => {
+                let (
+                    buyer_state_bump,
+                    escrow_payment_bump,
+                    buyer_price,
+                    token_size,
+                    buyer_state_expiry,
+                ) = unpack_args(rest)?;
+                Self::Buy {
+                    buyer_state_bump,
+                    escrow_payment_bump,
+                    buyer_price,
+                    token_size,
+                    buyer_state_expiry,
+                }
+            }
+            BUY_V2 => {
+                // Trailing extra args are left unread
+                let (buyer_price, token_size, buyer_state_expiry, buyer_creator_royalty_bp) =
+                    unpack_args(rest)?;
+                Self::BuyV2 {
+                    buyer_price,
+                    token_size,
+                    buyer_state_expiry,
+                    buyer_creator_royalty_bp,
+                }
+            }
+            SELL => {
+                let (
+                    seller_state_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                    seller_state_expiry,
+                ) = unpack_args(rest)?;
+                Self::Sell {
+                    seller_state_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                    seller_state_expiry,
+                }
+            }
+            CANCEL_BUY => {
+                let (buyer_price, token_size, buyer_state_expiry) = unpack_args(rest)?;
+                Self::CancelBuy {
+                    buyer_price,
+                    token_size,
+                    buyer_state_expiry,
+                }
+            }
+            CANCEL_SELL => {
+                let (buyer_price, token_size, seller_state_expiry) = unpack_args(rest)?;
+                Self::CancelSell {
+                    buyer_price,
+                    token_size,
+                    seller_state_expiry,
+                }
+            }
+            EXECUTE_SALE_V2 => {
+                let (
+                    escrow_payment_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                    buyer_state_expiry,
+                    seller_state_expiry,
+                    maker_fee_bp,
+                    taker_fee_bp,
+                ) = unpack_args(rest)?;
+                Self::ExecuteSaleV2 {
+                    escrow_payment_bump,
+                    program_as_signer_bump,
+                    buyer_price,
+                    token_size,
+                    buyer_state_expiry,
+                    seller_state_expiry,
+                    maker_fee_bp,
+                    taker_fee_bp,
+                }
+            }
+            DEPOSIT => {
+                let (escrow_payment_bump, amount) = unpack_args(rest)?;
+                Self::Deposit {
+                    escrow_payment_bump,
+                    amount,
+                }
+            }
+            WITHDRAW => {
+                let (escrow_payment_bump, amount) = unpack_args(rest)?;
+                Self::Withdraw {
+                    escrow_payment_bump,
+                    amount,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = MagicEdenInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let magic_eden_instruction = ui.clone();
+            match magic_eden_instruction {
+                MagicEdenInstruction::Buy { buyer_price, token_size, buyer_state_expiry, .. } => {
+                    // The PDA bumps are left out, they carry no information about the trade.
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "buy".to_string(),
+                            action: "nft.bid".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_state_expiry".to_string(),
+                                value: buyer_state_expiry.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MagicEdenInstruction::BuyV2 {
+                    buyer_price,
+                    token_size,
+                    buyer_state_expiry,
+                    buyer_creator_royalty_bp,
+                    ..
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "buy-v2".to_string(),
+                            action: "nft.bid".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_state_expiry".to_string(),
+                                value: buyer_state_expiry.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_creator_royalty_bp".to_string(),
+                                value: buyer_creator_royalty_bp.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MagicEdenInstruction::Sell { buyer_price, token_size, seller_state_expiry, .. } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "sell".to_string(),
+                            action: "nft.list".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "seller_state_expiry".to_string(),
+                                value: seller_state_expiry.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MagicEdenInstruction::CancelBuy { buyer_price, token_size, buyer_state_expiry } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "cancel-buy".to_string(),
+                            action: "nft.bid.cancel".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_state_expiry".to_string(),
+                                value: buyer_state_expiry.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MagicEdenInstruction::CancelSell {
+                    buyer_price,
+                    token_size,
+                    seller_state_expiry,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "cancel-sell".to_string(),
+                            action: "nft.list.cancel".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "seller_state_expiry".to_string(),
+                                value: seller_state_expiry.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MagicEdenInstruction::ExecuteSaleV2 {
+                    buyer_price,
+                    token_size,
+                    buyer_state_expiry,
+                    seller_state_expiry,
+                    maker_fee_bp,
+                    taker_fee_bp,
+                    ..
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "execute-sale-v2".to_string(),
+                            action: "nft.sale".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_state_expiry".to_string(),
+                                value: buyer_state_expiry.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "seller_state_expiry".to_string(),
+                                value: seller_state_expiry.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "maker_fee_bp".to_string(),
+                                value: maker_fee_bp.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "taker_fee_bp".to_string(),
+                                value: taker_fee_bp.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MagicEdenInstruction::Deposit { amount, .. } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit".to_string(),
+                            action: "nft.escrow.deposit".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MagicEdenInstruction::Withdraw { amount, .. } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw".to_string(),
+                            action: "nft.escrow.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/magic_eden_v2] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
@@ -0,0 +1,281 @@
+use std::convert::TryInto;
+
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "MERLuDFBMmsHnsBPZw2sDQZHvXFMwp8EdjudcU2HKky";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "add-liquidity",
+    "remove-liquidity",
+    "remove-liquidity-one-token",
+    "exchange",
+];
+
+/// Instructions of the Mercurial stable swap program this decoder interprets, a one byte tag
+/// followed by the little endian arguments. Pools hold two to four tokens and the per token
+/// amounts are not length prefixed, so their count follows from the data length.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MercurialInstruction {
+    /// Deposit the pool tokens in exchange for LP tokens.
+    AddLiquidity {
+        deposit_amounts: Vec<u64>,
+        min_mint_amount: u64,
+    },
+    /// Burn LP tokens in exchange for every pool token.
+    RemoveLiquidity {
+        unmint_amount: u64,
+        minimum_amounts: Vec<u64>,
+    },
+    /// Burn LP tokens in exchange for one of the pool tokens.
+    RemoveLiquidityOneToken {
+        unmint_amount: u64,
+        minimum_out_amount: u64,
+    },
+    /// Swap one pool token for another.
+    Exchange {
+        in_amount: u64,
+        minimum_out_amount: u64,
+    },
+}
+
+impl MercurialInstruction {
+    /// Unpacks a byte buffer into a MercurialInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match tag {
+            1 => {
+                let amounts = Self::unpack_u64s(rest)?;
+                let (&min_mint_amount, deposit_amounts) = amounts
+                    .split_last()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Self::AddLiquidity {
+                    deposit_amounts: deposit_amounts.to_vec(),
+                    min_mint_amount,
+                }
+            }
+            2 => {
+                let amounts = Self::unpack_u64s(rest)?;
+                let (&unmint_amount, minimum_amounts) = amounts
+                    .split_first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Self::RemoveLiquidity {
+                    unmint_amount,
+                    minimum_amounts: minimum_amounts.to_vec(),
+                }
+            }
+            3 => Self::RemoveLiquidityOneToken {
+                unmint_amount: Self::unpack_u64(rest, 0)?,
+                minimum_out_amount: Self::unpack_u64(rest, 8)?,
+            },
+            4 => Self::Exchange {
+                in_amount: Self::unpack_u64(rest, 0)?,
+                minimum_out_amount: Self::unpack_u64(rest, 8)?,
+            },
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    fn unpack_u64(input: &[u8], offset: usize) -> Result<u64, ProgramError> {
+        input
+            .get(offset..offset + 8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn unpack_u64s(input: &[u8]) -> Result<Vec<u64>, ProgramError> {
+        if input.len() % 8 != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        (0..input.len() / 8)
+            .map(|i| Self::unpack_u64(input, i * 8))
+            .collect()
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = MercurialInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let mercurial_instruction = ui.clone();
+            match mercurial_instruction {
+                MercurialInstruction::AddLiquidity { deposit_amounts, min_mint_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "add-liquidity".to_string(),
+                            action: "dex.liquidity.add".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "deposit_amounts".to_string(),
+                                value: serde_json::to_string(deposit_amounts.as_slice()).unwrap(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "min_mint_amount".to_string(),
+                                value: min_mint_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MercurialInstruction::RemoveLiquidity { unmint_amount, minimum_amounts } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "remove-liquidity".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "unmint_amount".to_string(),
+                                value: unmint_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_amounts".to_string(),
+                                value: serde_json::to_string(minimum_amounts.as_slice()).unwrap(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MercurialInstruction::RemoveLiquidityOneToken {
+                    unmint_amount,
+                    minimum_out_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "remove-liquidity-one-token".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "unmint_amount".to_string(),
+                                value: unmint_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_out_amount".to_string(),
+                                value: minimum_out_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MercurialInstruction::Exchange { in_amount, minimum_out_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "exchange".to_string(),
+                            action: "dex.swap".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "in_amount".to_string(),
+                                value: in_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_out_amount".to_string(),
+                                value: minimum_out_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/mercurial_stable_swap] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
@@ -0,0 +1,344 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::auction_house::instruction::AuctionHouseInstruction;
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "hausS13jsjafwWwGqZTUQRmWyvyxn9EQpqMwV1PBBmk";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "sell",
+    "buy",
+    "public-buy",
+    "execute-sale",
+    "execute-partial-sale",
+    "deposit",
+    "withdraw",
+    "cancel",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = AuctionHouseInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let auction_house_instruction = ui.clone();
+            match auction_house_instruction {
+                AuctionHouseInstruction::Sell { buyer_price, token_size, .. } => {
+                    // The PDA bumps are left out, they carry no information about the trade.
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "sell".to_string(),
+                            action: "nft.list".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AuctionHouseInstruction::Buy { buyer_price, token_size, .. } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "buy".to_string(),
+                            action: "nft.bid".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AuctionHouseInstruction::PublicBuy { buyer_price, token_size, .. } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "public-buy".to_string(),
+                            action: "nft.bid".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AuctionHouseInstruction::ExecuteSale { buyer_price, token_size, .. } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "execute-sale".to_string(),
+                            action: "nft.sale".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AuctionHouseInstruction::ExecutePartialSale {
+                    buyer_price,
+                    token_size,
+                    partial_order_size,
+                    partial_order_price,
+                    ..
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "execute-partial-sale".to_string(),
+                            action: "nft.sale".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "partial_order_size".to_string(),
+                                value: if let Some(v) = partial_order_size {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "partial_order_price".to_string(),
+                                value: if let Some(v) = partial_order_price {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AuctionHouseInstruction::Deposit { amount, .. } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit".to_string(),
+                            action: "nft.escrow.deposit".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AuctionHouseInstruction::Withdraw { amount, .. } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw".to_string(),
+                            action: "nft.escrow.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AuctionHouseInstruction::Cancel { buyer_price, token_size } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "cancel".to_string(),
+                            action: "nft.order.cancel".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "buyer_price".to_string(),
+                                value: buyer_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_size".to_string(),
+                                value: token_size.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/metaplex_auction_house] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
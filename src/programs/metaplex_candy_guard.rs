@@ -0,0 +1,350 @@
+use borsh::BorshDeserialize;
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::candy_guard::instruction::{CandyGuardInstruction, GuardType};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "Guard1JwRhJkVH6XZhzoYxeBVQe872VH6QggF4BWmS9g";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize",
+    "mint",
+    "mint-v2",
+    "route",
+    "set-authority",
+    "unwrap",
+    "update",
+    "withdraw",
+    "wrap",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = CandyGuardInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let candy_guard_instruction = ui.clone();
+            match candy_guard_instruction {
+                CandyGuardInstruction::Initialize { data } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "data".to_string(),
+                                value: base64::encode(&data),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CandyGuardInstruction::Mint { mint_args, label } => {
+                    // The group label tells which guard set applied to the mint. Payment guards are configured
+                    // in the candy guard account, the instruction only carries their optional mint arguments.
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "mint".to_string(),
+                            action: "nft.mint".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "group".to_string(),
+                                value: if let Some(v) = label {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "mint_args".to_string(),
+                                value: base64::encode(&mint_args),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CandyGuardInstruction::MintV2 { mint_args, label } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "mint-v2".to_string(),
+                            action: "nft.mint".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "group".to_string(),
+                                value: if let Some(v) = label {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "mint_args".to_string(),
+                                value: base64::encode(&mint_args),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CandyGuardInstruction::Route { args, label } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "route".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "guard".to_string(),
+                                value: match args.guard {
+                                    GuardType::BotTax => "bot_tax",
+                                    GuardType::SolPayment => "sol_payment",
+                                    GuardType::TokenPayment => "token_payment",
+                                    GuardType::StartDate => "start_date",
+                                    GuardType::ThirdPartySigner => "third_party_signer",
+                                    GuardType::TokenGate => "token_gate",
+                                    GuardType::Gatekeeper => "gatekeeper",
+                                    GuardType::EndDate => "end_date",
+                                    GuardType::AllowList => "allow_list",
+                                    GuardType::MintLimit => "mint_limit",
+                                    GuardType::NftPayment => "nft_payment",
+                                    GuardType::RedeemedAmount => "redeemed_amount",
+                                    GuardType::AddressGate => "address_gate",
+                                    GuardType::NftGate => "nft_gate",
+                                    GuardType::NftBurn => "nft_burn",
+                                    GuardType::TokenBurn => "token_burn",
+                                    GuardType::FreezeSolPayment => "freeze_sol_payment",
+                                    GuardType::FreezeTokenPayment => "freeze_token_payment",
+                                    GuardType::ProgramGate => "program_gate",
+                                    GuardType::Allocation => "allocation",
+                                    GuardType::Token2022Payment => "token_2022_payment",
+                                }
+                                .to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "group".to_string(),
+                                value: if let Some(v) = label {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "data".to_string(),
+                                value: base64::encode(&args.data),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "merkle_proof".to_string(),
+                                value: if let GuardType::AllowList = args.guard {
+                                    // The allow list route proves the minter is part of the merkle tree
+                                    match Vec::<[u8; 32]>::try_from_slice(&args.data) {
+                                        Ok(proof) => serde_json::to_string(
+                                            &proof
+                                                .iter()
+                                                .map(|node| bs58::encode(node).into_string())
+                                                .collect::<Vec<String>>(),
+                                        )
+                                        .unwrap()
+                                        .to_string(),
+                                        Err(_) => "".to_string(),
+                                    }
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CandyGuardInstruction::SetAuthority { new_authority } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-authority".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "new_authority".to_string(),
+                                value: new_authority.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CandyGuardInstruction::Unwrap => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "unwrap".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                CandyGuardInstruction::Update { data } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "update".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "data".to_string(),
+                                value: base64::encode(&data),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CandyGuardInstruction::Withdraw => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                CandyGuardInstruction::Wrap => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "wrap".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/metaplex_candy_guard] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
@@ -0,0 +1,500 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::candy_machine::instruction::{CandyMachineData, CandyMachineInstruction};
+use crate::programs::token_metadata::state::TokenStandard;
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize",
+    "initialize-v2",
+    "add-config-lines",
+    "update",
+    "set-authority",
+    "set-mint-authority",
+    "set-collection",
+    "set-collection-v2",
+    "set-token-standard",
+    "mint",
+    "mint-v2",
+    "withdraw",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = CandyMachineInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let candy_machine_instruction = ui.clone();
+            match candy_machine_instruction {
+                CandyMachineInstruction::Initialize { data } => {
+                    let mut properties = vec![];
+                    properties.extend(data_properties(&instruction, &data));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                CandyMachineInstruction::InitializeV2 { data, token_standard } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "token_standard".to_string(),
+                            value: match token_standard {
+                                TokenStandard::NonFungible => "non_fungible",
+                                TokenStandard::FungibleAsset => "fungible_asset",
+                                TokenStandard::Fungible => "fungible",
+                                TokenStandard::NonFungibleEdition => "non_fungible_edition",
+                                TokenStandard::ProgrammableNonFungible => "programmable_non_fungible",
+                                TokenStandard::ProgrammableNonFungibleEdition => "programmable_non_fungible_edition",
+                            }
+                            .to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(data_properties(&instruction, &data));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize-v2".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                CandyMachineInstruction::AddConfigLines { index, config_lines } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "index".to_string(),
+                            value: index.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    for (i, config_line) in config_lines.iter().enumerate() {
+                        // Config lines are written from `index` onwards
+                        let key_name = "config_lines/".to_owned() + &*(index as usize + i).to_string();
+
+                        properties.extend(vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: key_name.clone() + &"/name".to_owned(),
+                                        value: config_line.name.to_string(),
+                                        parent_key: key_name.clone(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: key_name.clone() + &"/uri".to_owned(),
+                                        value: config_line.uri.to_string(),
+                                        parent_key: key_name.clone(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                        ]);
+                    }
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "add-config-lines".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                CandyMachineInstruction::Update { data } => {
+                    let mut properties = vec![];
+                    properties.extend(data_properties(&instruction, &data));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "update".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                CandyMachineInstruction::SetAuthority { new_authority } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-authority".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "new_authority".to_string(),
+                                value: new_authority.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CandyMachineInstruction::SetMintAuthority => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-mint-authority".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                CandyMachineInstruction::SetCollection => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-collection".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                CandyMachineInstruction::SetCollectionV2 => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-collection-v2".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                CandyMachineInstruction::SetTokenStandard { token_standard } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-token-standard".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_standard".to_string(),
+                                value: match token_standard {
+                                    TokenStandard::NonFungible => "non_fungible",
+                                    TokenStandard::FungibleAsset => "fungible_asset",
+                                    TokenStandard::Fungible => "fungible",
+                                    TokenStandard::NonFungibleEdition => "non_fungible_edition",
+                                    TokenStandard::ProgrammableNonFungible => "programmable_non_fungible",
+                                    TokenStandard::ProgrammableNonFungibleEdition => "programmable_non_fungible_edition",
+                                }
+                                .to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                CandyMachineInstruction::Mint => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "mint".to_string(),
+                            action: "nft.mint".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                CandyMachineInstruction::MintV2 => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "mint-v2".to_string(),
+                            action: "nft.mint".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                CandyMachineInstruction::Withdraw => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/metaplex_candy_machine] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
+
+/// Flattens the candy machine configuration carried by the initialize and update instructions
+/// into properties.
+fn data_properties(instruction: &Instruction, data: &CandyMachineData) -> Vec<InstructionProperty> {
+    let mut properties = vec![
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "items_available".to_string(),
+            value: data.items_available.to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "symbol".to_string(),
+            value: data.symbol.to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "seller_fee_basis_points".to_string(),
+            value: data.seller_fee_basis_points.to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "max_supply".to_string(),
+            value: data.max_supply.to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "is_mutable".to_string(),
+            value: (data.is_mutable as i32).to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+    ];
+
+    for (i, creator) in data.creators.iter().enumerate() {
+        let key_name = "creators/".to_owned() + &*i.to_string();
+
+        properties.extend(vec![
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: key_name.clone() + &"/address".to_owned(),
+                value: creator.address.to_string(),
+                parent_key: key_name.clone(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: key_name.clone() + &"/verified".to_owned(),
+                value: (creator.verified as i32).to_string(),
+                parent_key: key_name.clone(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: key_name.clone() + &"/percentage_share".to_owned(),
+                value: creator.percentage_share.to_string(),
+                parent_key: key_name.clone(),
+                timestamp: instruction.timestamp.clone(),
+            },
+        ]);
+    }
+
+    if let Some(settings) = &data.config_line_settings {
+        properties.extend(vec![
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "prefix_name".to_string(),
+                value: settings.prefix_name.to_string(),
+                parent_key: "config_line_settings".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "name_length".to_string(),
+                value: settings.name_length.to_string(),
+                parent_key: "config_line_settings".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "prefix_uri".to_string(),
+                value: settings.prefix_uri.to_string(),
+                parent_key: "config_line_settings".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "uri_length".to_string(),
+                value: settings.uri_length.to_string(),
+                parent_key: "config_line_settings".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "is_sequential".to_string(),
+                value: (settings.is_sequential as i32).to_string(),
+                parent_key: "config_line_settings".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+        ]);
+    }
+
+    if let Some(settings) = &data.hidden_settings {
+        properties.extend(vec![
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "hidden_name".to_string(),
+                value: settings.name.to_string(),
+                parent_key: "hidden_settings".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "hidden_uri".to_string(),
+                value: settings.uri.to_string(),
+                parent_key: "hidden_settings".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "hidden_hash".to_string(),
+                value: hex::encode(settings.hash),
+                parent_key: "hidden_settings".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+        ]);
+    }
+
+    properties
+}
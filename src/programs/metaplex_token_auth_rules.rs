@@ -0,0 +1,215 @@
+use borsh::BorshDeserialize;
+use tracing::error;
+
+use crate::programs::token_auth_rules::instruction::{
+    CreateOrUpdateArgs, PuffRuleSetArgs, RuleSetInstruction, ValidateArgs, WriteToBufferArgs,
+};
+use crate::programs::token_auth_rules::payload::PayloadType;
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "create-or-update",
+    "validate",
+    "write-to-buffer",
+    "puff-rule-set",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = RuleSetInstruction::try_from_slice(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let rule_set_instruction = ui.clone();
+            match rule_set_instruction {
+                RuleSetInstruction::CreateOrUpdate(CreateOrUpdateArgs::V1 { serialized_rule_set }) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-or-update".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "serialized_rule_set".to_string(),
+                                value: base64::encode(&serialized_rule_set),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                RuleSetInstruction::Validate(ValidateArgs::V1 {
+                    operation,
+                    payload,
+                    update_rule_state,
+                    rule_set_revision,
+                }) => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "operation".to_string(),
+                            value: operation.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "update_rule_state".to_string(),
+                            value: (update_rule_state as i32).to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "rule_set_revision".to_string(),
+                            value: if let Some(v) = rule_set_revision {
+                                v.to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+
+                    // Every payload entry is keyed by the field name the rules look it up with
+                    for (name, value) in payload.map.iter() {
+                        properties.push(InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "payload/".to_owned() + &*name,
+                            value: match value {
+                                PayloadType::Pubkey(pubkey) => pubkey.to_string(),
+                                PayloadType::Seeds(seeds_vec) => serde_json::to_string(
+                                    &seeds_vec
+                                        .seeds
+                                        .iter()
+                                        .map(|seed| base64::encode(seed))
+                                        .collect::<Vec<String>>(),
+                                )
+                                .unwrap()
+                                .to_string(),
+                                PayloadType::MerkleProof(proof_info) => serde_json::to_string(
+                                    &proof_info
+                                        .proof
+                                        .iter()
+                                        .map(|node| bs58::encode(node).into_string())
+                                        .collect::<Vec<String>>(),
+                                )
+                                .unwrap()
+                                .to_string(),
+                                PayloadType::Number(number) => number.to_string(),
+                            },
+                            parent_key: "payload".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        });
+                    }
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "validate".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                RuleSetInstruction::WriteToBuffer(WriteToBufferArgs::V1 {
+                    serialized_rule_set,
+                    overwrite,
+                }) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "write-to-buffer".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "serialized_rule_set".to_string(),
+                                value: base64::encode(&serialized_rule_set),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "overwrite".to_string(),
+                                value: (overwrite as i32).to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                RuleSetInstruction::PuffRuleSet(PuffRuleSetArgs::V1 { rule_set_name }) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "puff-rule-set".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "rule_set_name".to_string(),
+                                value: rule_set_name.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            // If the instruction parsing is failing, bail out
+            error!("[spi-wrapper/programs/metaplex_token_auth_rules] Attempt to parse instruction from program {} \
+        failed due to {}.", instruction.program, err);
+
+            None
+        }
+    };
+}
@@ -0,0 +1,1006 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::token_metadata::instruction::{
+    DelegateArgs, MetadataInstruction, RevokeArgs, VerificationArgs,
+};
+use crate::programs::token_metadata::state::{
+    CollectionDetails, DataV2, PrintSupply, TokenStandard, UseMethod,
+};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+
+// The function names this decoder may emit, in instruction tag order.
+pub const FUNCTIONS: &[&str] = &[
+    "create-metadata-account",
+    "update-metadata-account",
+    "deprecated-create-master-edition",
+    "deprecated-mint-new-edition-from-master-edition-via-printing-token",
+    "update-primary-sale-happened-via-token",
+    "deprecated-set-reservation-list",
+    "deprecated-create-reservation-list",
+    "sign-metadata",
+    "deprecated-mint-printing-tokens-via-token",
+    "deprecated-mint-printing-tokens",
+    "create-master-edition",
+    "mint-new-edition-from-master-edition-via-token",
+    "convert-master-edition-v1-to-v2",
+    "mint-new-edition-from-master-edition-via-vault-proxy",
+    "puff-metadata",
+    "update-metadata-account-v2",
+    "create-metadata-account-v2",
+    "create-master-edition-v3",
+    "verify-collection",
+    "utilize",
+    "approve-use-authority",
+    "revoke-use-authority",
+    "unverify-collection",
+    "approve-collection-authority",
+    "revoke-collection-authority",
+    "set-and-verify-collection",
+    "freeze-delegated-account",
+    "thaw-delegated-account",
+    "remove-creator-verification",
+    "burn-nft",
+    "verify-sized-collection-item",
+    "unverify-sized-collection-item",
+    "set-and-verify-sized-collection-item",
+    "create-metadata-account-v3",
+    "set-collection-size",
+    "set-token-standard",
+    "bubblegum-set-collection-size",
+    "burn-edition-nft",
+    "create-escrow-account",
+    "close-escrow-account",
+    "transfer-out-of-escrow",
+    "burn",
+    "create",
+    "mint",
+    "delegate",
+    "revoke",
+    "lock",
+    "unlock",
+    "migrate",
+    "transfer",
+    "update",
+    "use",
+    "verify",
+    "unverify",
+    "collect",
+    "print",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = MetadataInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let metadata_instruction = ui.clone();
+            match metadata_instruction {
+                MetadataInstruction::CreateMetadataAccount { data, is_mutable } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "is_mutable".to_string(),
+                            value: (is_mutable as i32).to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(data_properties(&instruction, &data.to_v2()));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-metadata-account".to_string(),
+                            action: "nft.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                MetadataInstruction::UpdateMetadataAccount {
+                    data,
+                    update_authority,
+                    primary_sale_happened,
+                } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "update_authority".to_string(),
+                            value: if let Some(v) = update_authority {
+                                v.to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "primary_sale_happened".to_string(),
+                            value: if let Some(v) = primary_sale_happened {
+                                (v as i32).to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    if let Some(data) = data {
+                        properties.extend(data_properties(&instruction, &data.to_v2()));
+                    }
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "update-metadata-account".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                MetadataInstruction::CreateMasterEdition { max_supply } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-master-edition".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "max_supply".to_string(),
+                                value: if let Some(v) = max_supply {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::MintNewEditionFromMasterEditionViaToken { edition } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "mint-new-edition-from-master-edition-via-token".to_string(),
+                            action: "nft.mint".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "edition".to_string(),
+                                value: edition.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::UpdateMetadataAccountV2 {
+                    data,
+                    update_authority,
+                    primary_sale_happened,
+                    is_mutable,
+                } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "update_authority".to_string(),
+                            value: if let Some(v) = update_authority {
+                                v.to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "primary_sale_happened".to_string(),
+                            value: if let Some(v) = primary_sale_happened {
+                                (v as i32).to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "is_mutable".to_string(),
+                            value: if let Some(v) = is_mutable {
+                                (v as i32).to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    if let Some(data) = data {
+                        properties.extend(data_properties(&instruction, &data));
+                    }
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "update-metadata-account-v2".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                MetadataInstruction::CreateMetadataAccountV2 { data, is_mutable } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "is_mutable".to_string(),
+                            value: (is_mutable as i32).to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(data_properties(&instruction, &data));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-metadata-account-v2".to_string(),
+                            action: "nft.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                MetadataInstruction::CreateMasterEditionV3 { max_supply } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-master-edition-v3".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "max_supply".to_string(),
+                                value: if let Some(v) = max_supply {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::VerifyCollection => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "verify-collection".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                MetadataInstruction::UnverifyCollection => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "unverify-collection".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                MetadataInstruction::SetAndVerifyCollection => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-and-verify-collection".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                MetadataInstruction::VerifySizedCollectionItem => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "verify-sized-collection-item".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                MetadataInstruction::UnverifySizedCollectionItem => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "unverify-sized-collection-item".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                MetadataInstruction::SetAndVerifySizedCollectionItem => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-and-verify-sized-collection-item".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                MetadataInstruction::CreateMetadataAccountV3 {
+                    data,
+                    is_mutable,
+                    collection_details,
+                } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "is_mutable".to_string(),
+                            value: (is_mutable as i32).to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "collection_size".to_string(),
+                            value: if let Some(CollectionDetails::V1 { size }) = collection_details {
+                                size.to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(data_properties(&instruction, &data));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-metadata-account-v3".to_string(),
+                            action: "nft.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                MetadataInstruction::Create { asset_data, decimals, print_supply } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "primary_sale_happened".to_string(),
+                            value: (asset_data.primary_sale_happened as i32).to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "is_mutable".to_string(),
+                            value: (asset_data.is_mutable as i32).to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "token_standard".to_string(),
+                            value: match asset_data.token_standard {
+                                TokenStandard::NonFungible => "non_fungible",
+                                TokenStandard::FungibleAsset => "fungible_asset",
+                                TokenStandard::Fungible => "fungible",
+                                TokenStandard::NonFungibleEdition => "non_fungible_edition",
+                                TokenStandard::ProgrammableNonFungible => "programmable_non_fungible",
+                                TokenStandard::ProgrammableNonFungibleEdition => "programmable_non_fungible_edition",
+                            }
+                            .to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "collection_size".to_string(),
+                            value: if let Some(CollectionDetails::V1 { size }) = asset_data.collection_details {
+                                size.to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "rule_set".to_string(),
+                            value: if let Some(v) = asset_data.rule_set {
+                                v.to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "decimals".to_string(),
+                            value: if let Some(v) = decimals {
+                                v.to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "print_supply".to_string(),
+                            value: match print_supply {
+                                Some(PrintSupply::Zero) => "zero",
+                                Some(PrintSupply::Limited(_)) => "limited",
+                                Some(PrintSupply::Unlimited) => "unlimited",
+                                None => "",
+                            }
+                            .to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "max_print_supply".to_string(),
+                            value: if let Some(PrintSupply::Limited(v)) = print_supply {
+                                v.to_string()
+                            } else {
+                                "".to_string()
+                            },
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(data_properties(&instruction, &asset_data.to_data_v2()));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create".to_string(),
+                            action: "nft.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                MetadataInstruction::Mint { amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "mint".to_string(),
+                            action: "nft.mint".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::Delegate(args) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "delegate".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "role".to_string(),
+                                value: match args {
+                                    DelegateArgs::CollectionV1 => "collection_v1",
+                                    DelegateArgs::SaleV1 { .. } => "sale_v1",
+                                    DelegateArgs::TransferV1 { .. } => "transfer_v1",
+                                    DelegateArgs::DataV1 => "data_v1",
+                                    DelegateArgs::UtilityV1 { .. } => "utility_v1",
+                                    DelegateArgs::StakingV1 { .. } => "staking_v1",
+                                    DelegateArgs::StandardV1 { .. } => "standard_v1",
+                                    DelegateArgs::LockedTransferV1 { .. } => "locked_transfer_v1",
+                                    DelegateArgs::ProgrammableConfigV1 => "programmable_config_v1",
+                                    DelegateArgs::AuthorityItemV1 => "authority_item_v1",
+                                    DelegateArgs::DataItemV1 => "data_item_v1",
+                                    DelegateArgs::CollectionItemV1 => "collection_item_v1",
+                                    DelegateArgs::ProgrammableConfigItemV1 => "programmable_config_item_v1",
+                                }
+                                .to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: match args {
+                                    DelegateArgs::SaleV1 { amount }
+                                    | DelegateArgs::TransferV1 { amount }
+                                    | DelegateArgs::UtilityV1 { amount }
+                                    | DelegateArgs::StakingV1 { amount }
+                                    | DelegateArgs::StandardV1 { amount }
+                                    | DelegateArgs::LockedTransferV1 { amount, .. } => amount.to_string(),
+                                    _ => "".to_string(),
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "locked_address".to_string(),
+                                value: if let DelegateArgs::LockedTransferV1 { locked_address, .. } = args {
+                                    locked_address.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::Revoke(args) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "revoke".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "role".to_string(),
+                                value: match args {
+                                    RevokeArgs::CollectionV1 => "collection_v1",
+                                    RevokeArgs::SaleV1 => "sale_v1",
+                                    RevokeArgs::TransferV1 => "transfer_v1",
+                                    RevokeArgs::DataV1 => "data_v1",
+                                    RevokeArgs::UtilityV1 => "utility_v1",
+                                    RevokeArgs::StakingV1 => "staking_v1",
+                                    RevokeArgs::StandardV1 => "standard_v1",
+                                    RevokeArgs::LockedTransferV1 => "locked_transfer_v1",
+                                    RevokeArgs::ProgrammableConfigV1 => "programmable_config_v1",
+                                    RevokeArgs::MigrationV1 => "migration_v1",
+                                    RevokeArgs::AuthorityItemV1 => "authority_item_v1",
+                                    RevokeArgs::DataItemV1 => "data_item_v1",
+                                    RevokeArgs::CollectionItemV1 => "collection_item_v1",
+                                    RevokeArgs::ProgrammableConfigItemV1 => "programmable_config_item_v1",
+                                }
+                                .to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::Transfer { amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "transfer".to_string(),
+                            action: "nft.transfer".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::Verify(args) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "verify".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "verification_type".to_string(),
+                                value: match args {
+                                    VerificationArgs::CreatorV1 => "creator_v1",
+                                    VerificationArgs::CollectionV1 => "collection_v1",
+                                }
+                                .to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::Unverify(args) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "unverify".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "verification_type".to_string(),
+                                value: match args {
+                                    VerificationArgs::CreatorV1 => "creator_v1",
+                                    VerificationArgs::CollectionV1 => "collection_v1",
+                                }
+                                .to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                MetadataInstruction::Other(tag) => {
+                    // The remaining instructions are reported by name only, FUNCTIONS is in tag order.
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: FUNCTIONS[tag as usize].to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/metaplex_token_metadata] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
+
+/// Flattens the metadata carried by the create and update instructions into properties.
+fn data_properties(instruction: &Instruction, data: &DataV2) -> Vec<InstructionProperty> {
+    let mut properties = vec![
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "name".to_string(),
+            value: data.name.trim_matches(char::from(0)).to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "symbol".to_string(),
+            value: data.symbol.trim_matches(char::from(0)).to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "uri".to_string(),
+            value: data.uri.trim_matches(char::from(0)).to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "seller_fee_basis_points".to_string(),
+            value: data.seller_fee_basis_points.to_string(),
+            parent_key: "data".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+    ];
+
+    if let Some(creators) = &data.creators {
+        for (i, creator) in creators.iter().enumerate() {
+            let key_name = "creators/".to_owned() + &*i.to_string();
+
+            properties.extend(vec![
+                InstructionProperty {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    key: key_name.clone() + &"/address".to_owned(),
+                    value: creator.address.to_string(),
+                    parent_key: key_name.clone(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                InstructionProperty {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    key: key_name.clone() + &"/verified".to_owned(),
+                    value: (creator.verified as i32).to_string(),
+                    parent_key: key_name.clone(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                InstructionProperty {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    key: key_name.clone() + &"/share".to_owned(),
+                    value: creator.share.to_string(),
+                    parent_key: key_name.clone(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+            ]);
+        }
+    }
+
+    if let Some(collection) = &data.collection {
+        properties.extend(vec![
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "collection_key".to_string(),
+                value: collection.key.to_string(),
+                parent_key: "collection".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "collection_verified".to_string(),
+                value: (collection.verified as i32).to_string(),
+                parent_key: "collection".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+        ]);
+    }
+
+    if let Some(uses) = &data.uses {
+        properties.extend(vec![
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "use_method".to_string(),
+                value: match uses.use_method {
+                    UseMethod::Burn => "burn",
+                    UseMethod::Multiple => "multiple",
+                    UseMethod::Single => "single",
+                }
+                .to_string(),
+                parent_key: "uses".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "uses_remaining".to_string(),
+                value: uses.remaining.to_string(),
+                parent_key: "uses".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "uses_total".to_string(),
+                value: uses.total.to_string(),
+                parent_key: "uses".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+        ]);
+    }
+
+    properties
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::programs::token_metadata::instruction::{
+        CREATE, CREATE_METADATA_ACCOUNT_V3, LAST_TAG, TRANSFER, UPDATE_METADATA_ACCOUNT_V2,
+    };
+
+    #[test]
+    fn test_functions_are_in_tag_order() {
+        // Instructions without a dedicated arm are named by indexing FUNCTIONS with their tag.
+        assert_eq!(FUNCTIONS.len(), LAST_TAG as usize + 1);
+        assert_eq!(
+            FUNCTIONS[UPDATE_METADATA_ACCOUNT_V2 as usize],
+            "update-metadata-account-v2"
+        );
+        assert_eq!(
+            FUNCTIONS[CREATE_METADATA_ACCOUNT_V3 as usize],
+            "create-metadata-account-v3"
+        );
+        assert_eq!(FUNCTIONS[CREATE as usize], "create");
+        assert_eq!(FUNCTIONS[TRANSFER as usize], "transfer");
+    }
+}
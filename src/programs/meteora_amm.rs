@@ -0,0 +1,360 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB";
+
+pub const SWAP: Discriminator = [248, 198, 158, 145, 225, 117, 135, 200];
+pub const ADD_BALANCE_LIQUIDITY: Discriminator = [168, 227, 50, 62, 189, 171, 84, 176];
+pub const ADD_IMBALANCE_LIQUIDITY: Discriminator = [79, 35, 122, 84, 173, 15, 93, 191];
+pub const REMOVE_BALANCE_LIQUIDITY: Discriminator = [133, 109, 44, 179, 56, 238, 114, 33];
+pub const REMOVE_LIQUIDITY_SINGLE_SIDE: Discriminator = [84, 84, 177, 66, 254, 185, 10, 251];
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "swap",
+    "add-balance-liquidity",
+    "add-imbalance-liquidity",
+    "remove-balance-liquidity",
+    "remove-liquidity-single-side",
+];
+
+/// Instructions of the Meteora dynamic pool program this decoder interprets. The pool tokens are
+/// deposited into Meteora vaults.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AmmInstruction {
+    /// Swap one pool token for the other.
+    Swap {
+        in_amount: u64,
+        minimum_out_amount: u64,
+    },
+    /// Deposit both tokens at the pool ratio in exchange for LP tokens.
+    AddBalanceLiquidity {
+        pool_token_amount: u64,
+        maximum_token_a_amount: u64,
+        maximum_token_b_amount: u64,
+    },
+    /// Deposit any amount of both tokens in exchange for LP tokens.
+    AddImbalanceLiquidity {
+        minimum_pool_token_amount: u64,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    },
+    /// Burn LP tokens in exchange for both tokens at the pool ratio.
+    RemoveBalanceLiquidity {
+        pool_token_amount: u64,
+        minimum_a_token_out: u64,
+        minimum_b_token_out: u64,
+    },
+    /// Burn LP tokens in exchange for one of the tokens.
+    RemoveLiquiditySingleSide {
+        pool_token_amount: u64,
+        minimum_out_amount: u64,
+    },
+}
+
+impl AmmInstruction {
+    /// Unpacks a byte buffer into an AmmInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            SWAP => {
+                let (in_amount, minimum_out_amount) = unpack_args(rest)?;
+                Self::Swap {
+                    in_amount,
+                    minimum_out_amount,
+                }
+            }
+            ADD_BALANCE_LIQUIDITY => {
+                let (pool_token_amount, maximum_token_a_amount, maximum_token_b_amount) =
+                    unpack_args(rest)?;
+                Self::AddBalanceLiquidity {
+                    pool_token_amount,
+                    maximum_token_a_amount,
+                    maximum_token_b_amount,
+                }
+            }
+            ADD_IMBALANCE_LIQUIDITY => {
+                let (minimum_pool_token_amount, token_a_amount, token_b_amount) =
+                    unpack_args(rest)?;
+                Self::AddImbalanceLiquidity {
+                    minimum_pool_token_amount,
+                    token_a_amount,
+                    token_b_amount,
+                }
+            }
+            REMOVE_BALANCE_LIQUIDITY => {
+                let (pool_token_amount, minimum_a_token_out, minimum_b_token_out) =
+                    unpack_args(rest)?;
+                Self::RemoveBalanceLiquidity {
+                    pool_token_amount,
+                    minimum_a_token_out,
+                    minimum_b_token_out,
+                }
+            }
+            REMOVE_LIQUIDITY_SINGLE_SIDE => {
+                let (pool_token_amount, minimum_out_amount) = unpack_args(rest)?;
+                Self::RemoveLiquiditySingleSide {
+                    pool_token_amount,
+                    minimum_out_amount,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = AmmInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let amm_instruction = ui.clone();
+            match amm_instruction {
+                AmmInstruction::Swap { in_amount, minimum_out_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "swap".to_string(),
+                            action: "dex.swap".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "in_amount".to_string(),
+                                value: in_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_out_amount".to_string(),
+                                value: minimum_out_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::AddBalanceLiquidity {
+                    pool_token_amount,
+                    maximum_token_a_amount,
+                    maximum_token_b_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "add-balance-liquidity".to_string(),
+                            action: "dex.liquidity.add".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "pool_token_amount".to_string(),
+                                value: pool_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "maximum_token_a_amount".to_string(),
+                                value: maximum_token_a_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "maximum_token_b_amount".to_string(),
+                                value: maximum_token_b_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::AddImbalanceLiquidity {
+                    minimum_pool_token_amount,
+                    token_a_amount,
+                    token_b_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "add-imbalance-liquidity".to_string(),
+                            action: "dex.liquidity.add".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_pool_token_amount".to_string(),
+                                value: minimum_pool_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_a_amount".to_string(),
+                                value: token_a_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_b_amount".to_string(),
+                                value: token_b_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::RemoveBalanceLiquidity {
+                    pool_token_amount,
+                    minimum_a_token_out,
+                    minimum_b_token_out,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "remove-balance-liquidity".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "pool_token_amount".to_string(),
+                                value: pool_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_a_token_out".to_string(),
+                                value: minimum_a_token_out.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_b_token_out".to_string(),
+                                value: minimum_b_token_out.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::RemoveLiquiditySingleSide {
+                    pool_token_amount,
+                    minimum_out_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "remove-liquidity-single-side".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "pool_token_amount".to_string(),
+                                value: pool_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_out_amount".to_string(),
+                                value: minimum_out_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/meteora_amm] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
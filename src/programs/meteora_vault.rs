@@ -0,0 +1,218 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "24Uqj9JCLxUeoC3hGfh5W3s9FM9uCHDS2SG3LYwBpyTi";
+
+pub const DEPOSIT: Discriminator = [242, 35, 198, 137, 82, 225, 242, 182];
+pub const WITHDRAW: Discriminator = [183, 18, 70, 156, 148, 109, 161, 34];
+pub const WITHDRAW_DIRECTLY_FROM_STRATEGY: Discriminator = [201, 141, 146, 46, 173, 116, 198, 22];
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "deposit",
+    "withdraw",
+    "withdraw-directly-from-strategy",
+];
+
+/// Instructions of the Meteora dynamic vault program this decoder interprets. Strategy
+/// management is left out.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VaultInstruction {
+    /// Deposit tokens into the vault in exchange for LP tokens.
+    Deposit {
+        token_amount: u64,
+        minimum_lp_token_amount: u64,
+    },
+    /// Burn LP tokens in exchange for the vault's tokens.
+    Withdraw {
+        unmint_amount: u64,
+        min_out_amount: u64,
+    },
+    /// Burn LP tokens in exchange for tokens taken out of one of the vault's strategies.
+    WithdrawDirectlyFromStrategy {
+        unmint_amount: u64,
+        min_out_amount: u64,
+    },
+}
+
+impl VaultInstruction {
+    /// Unpacks a byte buffer into a VaultInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            DEPOSIT => {
+                let (token_amount, minimum_lp_token_amount) = unpack_args(rest)?;
+                Self::Deposit {
+                    token_amount,
+                    minimum_lp_token_amount,
+                }
+            }
+            WITHDRAW => {
+                let (unmint_amount, min_out_amount) = unpack_args(rest)?;
+                Self::Withdraw {
+                    unmint_amount,
+                    min_out_amount,
+                }
+            }
+            WITHDRAW_DIRECTLY_FROM_STRATEGY => {
+                let (unmint_amount, min_out_amount) = unpack_args(rest)?;
+                Self::WithdrawDirectlyFromStrategy {
+                    unmint_amount,
+                    min_out_amount,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = VaultInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let vault_instruction = ui.clone();
+            match vault_instruction {
+                VaultInstruction::Deposit { token_amount, minimum_lp_token_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit".to_string(),
+                            action: "vault.deposit".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_amount".to_string(),
+                                value: token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_lp_token_amount".to_string(),
+                                value: minimum_lp_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                VaultInstruction::Withdraw { unmint_amount, min_out_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw".to_string(),
+                            action: "vault.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "unmint_amount".to_string(),
+                                value: unmint_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "min_out_amount".to_string(),
+                                value: min_out_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                VaultInstruction::WithdrawDirectlyFromStrategy {
+                    unmint_amount,
+                    min_out_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-directly-from-strategy".to_string(),
+                            action: "vault.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "unmint_amount".to_string(),
+                                value: unmint_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "min_out_amount".to_string(),
+                                value: min_out_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/meteora_vault] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
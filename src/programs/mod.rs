@@ -1,15 +1,55 @@
+pub mod account_compression;
+pub mod anchor;
+pub mod auction_house;
 pub mod bpf_loader;
 pub mod bpf_loader_upgradeable;
+pub mod candy_guard;
+pub mod candy_machine;
+pub mod crema_clmm;
+pub mod governance;
+pub mod lifinity_swap;
+pub mod magic_eden_v2;
+pub mod mercurial_stable_swap;
+pub mod metaplex_auction_house;
+pub mod metaplex_candy_guard;
+pub mod metaplex_candy_machine;
+pub mod metaplex_token_auth_rules;
+pub mod metaplex_token_metadata;
+pub mod meteora_amm;
+pub mod meteora_vault;
+pub mod native_account_compression;
 pub mod native_associated_token_account;
+pub mod native_compute_budget;
 pub mod native_config;
+pub mod native_ed25519;
+pub mod native_governance;
 pub mod native_loader;
+pub mod native_memo;
+pub mod native_noop;
+pub mod native_record;
 pub mod native_secp256k1;
 pub mod native_stake;
+pub mod native_stake_pool;
 pub mod native_system;
 pub mod native_token;
+pub mod native_token_2022;
 pub mod native_token_swap;
 pub mod native_token_lending;
 pub mod native_vote;
+pub mod orca_aquafarm;
+pub mod orca_token_swap;
+pub mod orca_whirlpool;
+pub mod quarry_mine;
+pub mod raydium_amm;
+pub mod raydium_clmm;
+pub mod raydium_farm;
+pub mod saber_stable_swap;
 pub mod serum_market;
 pub mod solend;
-pub mod solend_token_lending;
\ No newline at end of file
+pub mod solend_token_lending;
+pub mod stake_pool;
+pub mod tensorswap;
+pub mod token_2022;
+pub mod token_auth_rules;
+pub mod token_metadata;
+pub mod whirlpool;
\ No newline at end of file
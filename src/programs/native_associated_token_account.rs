@@ -1,10 +1,68 @@
-use bincode::deserialize;
+use solana_program::program_error::ProgramError;
 use tracing::error;
 
-use crate::{InstructionProperty, Instruction, InstructionSet, InstructionFunction};
+use crate::{Instruction, InstructionFunction, InstructionSet};
 
 pub const PROGRAM_ADDRESS: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "create",
+    "create-idempotent",
+    "recover-nested",
+];
+
+/// Instructions supported by the AssociatedTokenAccount program, none of which carry data past
+/// their tag. The wallet, mint and derived associated token account are passed as accounts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssociatedTokenAccountInstruction {
+    /// Creates an associated token account for the given wallet address and token mint.
+    /// Returns an error if the account exists.
+    ///
+    ///   0. `[writeable,signer]` Funding account (must be a system account)
+    ///   1. `[writeable]` Associated token account address to be created
+    ///   2. `[]` Wallet address for the new associated token account
+    ///   3. `[]` The token mint for the new associated token account
+    ///   4. `[]` System program
+    ///   5. `[]` SPL Token program
+    Create,
+    /// Creates an associated token account for the given wallet address and token mint,
+    /// if it doesn't already exist.  Returns an error if the account exists,
+    /// but with a different owner.
+    ///
+    ///   0. `[writeable,signer]` Funding account (must be a system account)
+    ///   1. `[writeable]` Associated token account address to be created
+    ///   2. `[]` Wallet address for the new associated token account
+    ///   3. `[]` The token mint for the new associated token account
+    ///   4. `[]` System program
+    ///   5. `[]` SPL Token program
+    CreateIdempotent,
+    /// Transfers from and closes a nested associated token account: an
+    /// associated token account owned by an associated token account.
+    ///
+    ///   0. `[writeable]` Nested associated token account, must be owned by `3`
+    ///   1. `[]` Token mint for the nested associated token account
+    ///   2. `[writeable]` Wallet's associated token account
+    ///   3. `[]` Owner associated token account address, must be owned by `5`
+    ///   4. `[]` Token mint for the owner associated token account
+    ///   5. `[writeable, signer]` Wallet address for the owner associated token account
+    ///   6. `[]` SPL Token program
+    RecoverNested,
+}
+
+impl AssociatedTokenAccountInstruction {
+    /// Unpacks a byte buffer into an AssociatedTokenAccountInstruction. Instructions created
+    /// before the tag was introduced carry no data and are creates.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.first() {
+            None | Some(0) => Ok(Self::Create),
+            Some(1) => Ok(Self::CreateIdempotent),
+            Some(2) => Ok(Self::RecoverNested),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -13,107 +71,82 @@ pub async fn fragment_instruction(
     // The instruction
     instruction: Instruction,
 ) -> Option<InstructionSet> {
-    let atadr = deserialize::<solana_program::instruction::Instruction>(
-        &instruction.data.as_slice());
+    let unpack_result = AssociatedTokenAccountInstruction::unpack(&instruction.data);
 
-    return match atadr {
-        Ok(ref ati) => {
-            let associated_token_instruction = ati.clone();
-            // Create an associated token account for the given wallet address and token mint
-            //
-            // Accounts expected by this instruction:
-            //
-            //   0. `[writeable,signer]` Funding account (must be a system account)
-            //   1. `[writeable]` Associated token account address to be created
-            //   2. `[]` Wallet address for the new associated token account
-            //   3. `[]` The token mint for the new associated token account
-            //   4. `[]` System program
-            //   5. `[]` SPL Token program
-            //   6. `[]` Rent sysvar
-            let account_sets: Vec<Vec<InstructionProperty>> = associated_token_instruction.accounts
-                .into_iter().map(|am| {
-                vec![
-                    InstructionProperty {
-                        tx_instruction_id: instruction.tx_instruction_id.clone(),
-                        transaction_hash: instruction.transaction_hash.clone(),
-                        parent_index: instruction.parent_index.clone(),
-                        key: "pubkey".to_string(),
-                        value: am.pubkey.to_string(),
-                        parent_key: "".to_string(),
-                        timestamp: instruction.timestamp.clone(),
-                    },
-                    InstructionProperty {
-                        tx_instruction_id: instruction.tx_instruction_id.clone(),
-                        transaction_hash: instruction.transaction_hash.clone(),
-                        parent_index: instruction.parent_index.clone(),
-                        key: "is_signer".to_string(),
-                        value: if am.is_signer {
-                            "1".to_string()
-                        } else {
-                            "0".to_string()
+    return match unpack_result {
+        Ok(ref ui) => {
+            let associated_token_instruction = ui.clone();
+            match associated_token_instruction {
+                AssociatedTokenAccountInstruction::Create => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create".to_string(),
+                            action: "token.account.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
                         },
-                        parent_key: "".to_string(),
-                        timestamp: instruction.timestamp.clone(),
-                    },
-                    InstructionProperty {
-                        tx_instruction_id: instruction.tx_instruction_id.clone(),
-                        transaction_hash: instruction.transaction_hash.clone(),
-                        parent_index: instruction.parent_index.clone(),
-                        key: "is_writable".to_string(),
-                        value: if am.is_writable {
-                            "1".to_string()
-                        } else {
-                            "0".to_string()
+                        properties: vec![],
+                    })
+                }
+                AssociatedTokenAccountInstruction::CreateIdempotent => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-idempotent".to_string(),
+                            action: "token.account.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
                         },
-                        parent_key: "".to_string(),
-                        timestamp: instruction.timestamp.clone(),
-                    }
-                ]
-            }).collect();
-
-            let mut properties = vec![
-                InstructionProperty {
-                    tx_instruction_id: instruction.tx_instruction_id.clone(),
-                    transaction_hash: instruction.transaction_hash.clone(),
-                    parent_index: instruction.parent_index.clone(),
-                    key: "data".to_string(),
-                    value: bs58::encode(associated_token_instruction.data).into_string(),
-                    parent_key: "".to_string(),
-                    timestamp: instruction.timestamp.clone(),
-                },
-                InstructionProperty {
-                    tx_instruction_id: instruction.tx_instruction_id.clone(),
-                    transaction_hash: instruction.transaction_hash.clone(),
-                    parent_index: instruction.parent_index.clone(),
-                    key: "program_id".to_string(),
-                    value: associated_token_instruction.program_id.to_string(),
-                    parent_key: "".to_string(),
-                    timestamp: instruction.timestamp.clone(),
+                        properties: vec![],
+                    })
+                }
+                AssociatedTokenAccountInstruction::RecoverNested => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "recover-nested".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
                 }
-            ];
-
-            for ac in account_sets {
-                properties.extend(ac);
             }
-
-            Some(InstructionSet {
-                function: InstructionFunction {
-                    tx_instruction_id: instruction.tx_instruction_id.clone(),
-                    transaction_hash: instruction.transaction_hash.clone(),
-                    parent_index: instruction.parent_index.clone(),
-                    program: instruction.program.clone(),
-                    function_name: "".to_string(),
-                    timestamp: instruction.timestamp
-                },
-                properties
-            })
         }
         Err(err) => {
-            // If the instruction parsing is failing, bail out
-            error!("[spi-wrapper/bpf_loader] Attempt to parse instruction from program {} failed due to \
-        {}.", instruction.program, err);
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/native_associated_token_account] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
 
             None
         }
-    }
-}
\ No newline at end of file
+    };
+}
@@ -7,6 +7,11 @@ use crate::{InstructionProperty, Instruction, InstructionSet, InstructionFunctio
 
 pub const PROGRAM_ADDRESS: &str = "Config1111111111111111111111111111111111111";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "",
+];
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -29,6 +34,7 @@ pub async fn fragment_instruction(
                     parent_index: instruction.parent_index.clone(),
                     program: instruction.program.clone(),
                     function_name: "".to_string(),
+                    action: "".to_string(),
                     timestamp: instruction.timestamp.clone(),
                 },
                 properties: vec![],
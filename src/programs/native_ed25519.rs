@@ -0,0 +1,254 @@
+use std::convert::TryInto;
+
+use solana_program::instruction::CompiledInstruction;
+use tracing::{error, info};
+
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "Ed25519SigVerify111111111111111111111111111";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "verify-signatures",
+];
+
+// Adapted from ed25519_instruction
+// https://github.com/solana-labs/solana/blob/v1.10.0/sdk/src/ed25519_instruction.rs
+pub const PUBKEY_SERIALIZED_SIZE: usize = 32;
+pub const SIGNATURE_SERIALIZED_SIZE: usize = 64;
+pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+// The signature count is followed by a padding byte so the offsets are aligned
+pub const SIGNATURE_OFFSETS_START: usize = 2;
+
+// An instruction index of u16::MAX refers to the ed25519 instruction itself.
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Ed25519SignatureOffsets {
+    signature_offset: u16,             // offset to ed25519 signature of 64 bytes
+    signature_instruction_index: u16,  // instruction index to find signature
+    public_key_offset: u16,            // offset to public key of 32 bytes
+    public_key_instruction_index: u16, // instruction index to find public key
+    message_data_offset: u16,          // offset to start of message data
+    message_data_size: u16,            // size of message data
+    message_instruction_index: u16,    // index of instruction data to get message data
+}
+
+impl Ed25519SignatureOffsets {
+    fn unpack(input: &[u8]) -> Option<Self> {
+        let field = |idx: usize| -> Option<u16> {
+            input.get(idx * 2..idx * 2 + 2)
+                .and_then(|slice| slice.try_into().ok())
+                .map(u16::from_le_bytes)
+        };
+
+        Some(Ed25519SignatureOffsets {
+            signature_offset: field(0)?,
+            signature_instruction_index: field(1)?,
+            public_key_offset: field(2)?,
+            public_key_instruction_index: field(3)?,
+            message_data_offset: field(4)?,
+            message_data_size: field(5)?,
+            message_instruction_index: field(6)?,
+        })
+    }
+
+    fn refers_to_other_instructions(&self) -> bool {
+        self.signature_instruction_index != CURRENT_INSTRUCTION_INDEX
+            || self.public_key_instruction_index != CURRENT_INSTRUCTION_INDEX
+            || self.message_instruction_index != CURRENT_INSTRUCTION_INDEX
+    }
+}
+
+/// Whether any signature is read from an instruction other than the ed25519 one itself, which
+/// can only be decoded along with the transaction's compiled instructions.
+pub fn refers_to_other_instructions(data: &[u8]) -> bool {
+    let count = data.first().map_or(0, |count| *count as usize);
+
+    (0..count)
+        .filter_map(|i| {
+            let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+            data.get(start..start + SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+        })
+        .filter_map(Ed25519SignatureOffsets::unpack)
+        .any(|offsets| offsets.refers_to_other_instructions())
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+    // The instructions that were part of the transaction, in order, when known.
+    instructions: Option<&[CompiledInstruction]>
+) -> Option<InstructionSet> {
+    // Without the other instructions only self-contained signatures can be read.
+    if instructions.is_none() && refers_to_other_instructions(&instruction.data) {
+        return None
+    }
+    let instructions = instructions.unwrap_or(&[]);
+
+    // Create the instruction
+    let mut interpreted_instruction_set = InstructionSet {
+        function: InstructionFunction {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            program: instruction.program.clone(),
+            function_name: "verify-signatures".to_string(),
+            action: "".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        properties: vec![],
+    };
+
+    // The first element within data slice tells us the number of signatures, followed by a
+    // padding byte.
+    if instruction.data.len() < SIGNATURE_OFFSETS_START {
+        info!(
+            "[spi-wrapper/programs/native_ed25519] INFO: On-chain failed instruction \
+        found -> PrecompileError::InvalidInstructionDataSize"
+        );
+
+        return Some(interpreted_instruction_set)
+    }
+    let count = instruction.data[0] as usize;
+    let expected_data_size = SIGNATURE_OFFSETS_START + count * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+    // On-chain failed instruction detected, safe anyways.
+    if instruction.data.len() < expected_data_size {
+        info!(
+            "[spi-wrapper/programs/native_ed25519] INFO: On-chain failed instruction \
+        found -> PrecompileError::InvalidInstructionDataSize"
+        );
+
+        return Some(interpreted_instruction_set)
+    }
+
+    interpreted_instruction_set.properties
+        .push(InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "num_signatures".to_string(),
+            value: count.to_string(),
+            parent_key: "".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        });
+
+    // Gather every instruction's data.
+    let instruction_datas: Vec<&[u8]> = instructions
+        .iter()
+        .map(|instruction| instruction.data.as_ref())
+        .collect();
+
+    for i in 0..count {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let end = start + SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+        let offsets = match Ed25519SignatureOffsets::unpack(&instruction.data[start..end]) {
+            Some(offsets) => offsets,
+            None => {
+                error!("{}",
+                    "[spi-wrapper/programs/native_ed25519] FATAL: The signature offsets could not \
+                be read."
+                        .to_string(),
+                );
+
+                return None
+            }
+        };
+
+        let key_name = "signatures/".to_owned() + &*i.to_string();
+
+        // Parse out the signature, public key and message
+        let slices = (
+            get_data_slice(
+                &instruction.data,
+                &instruction_datas,
+                offsets.signature_instruction_index,
+                offsets.signature_offset,
+                SIGNATURE_SERIALIZED_SIZE,
+            ),
+            get_data_slice(
+                &instruction.data,
+                &instruction_datas,
+                offsets.public_key_instruction_index,
+                offsets.public_key_offset,
+                PUBKEY_SERIALIZED_SIZE,
+            ),
+            get_data_slice(
+                &instruction.data,
+                &instruction_datas,
+                offsets.message_instruction_index,
+                offsets.message_data_offset,
+                offsets.message_data_size as usize,
+            ),
+        );
+
+        if let (Some(signature), Some(public_key), Some(message)) = slices {
+            interpreted_instruction_set.properties.extend(vec![
+                InstructionProperty {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    key: key_name.clone() + &"/public_key".to_owned(),
+                    value: bs58::encode(public_key).into_string(),
+                    parent_key: key_name.clone(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                InstructionProperty {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    key: key_name.clone() + &"/signature".to_owned(),
+                    value: bs58::encode(signature).into_string(),
+                    parent_key: key_name.clone(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                InstructionProperty {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    key: key_name.clone() + &"/message".to_owned(),
+                    value: hex::encode(message),
+                    parent_key: key_name.clone(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+            ]);
+        } else {
+            info!(
+                "[spi-wrapper/programs/native_ed25519] INFO: On-chain failed instruction \
+        found because the signature, public key or message slice cannot be parsed. -> \
+        PrecompileError::InvalidDataOffsets"
+            );
+
+            return Some(interpreted_instruction_set)
+        }
+    }
+
+    Some(interpreted_instruction_set)
+}
+
+// Adapted from ed25519_instruction
+// https://github.com/solana-labs/solana/blob/v1.10.0/sdk/src/ed25519_instruction.rs#L142
+fn get_data_slice<'a>(
+    data: &'a [u8],
+    instruction_datas: &'a [&[u8]],
+    instruction_index: u16,
+    offset_start: u16,
+    size: usize,
+) -> Option<&'a [u8]> {
+    let instruction = if instruction_index == CURRENT_INSTRUCTION_INDEX {
+        data
+    } else {
+        *instruction_datas.get(instruction_index as usize)?
+    };
+
+    let start = offset_start as usize;
+    let end = start.saturating_add(size);
+
+    instruction.get(start..end)
+}
@@ -0,0 +1,1149 @@
+use borsh::BorshDeserialize;
+use tracing::error;
+
+use crate::programs::governance::instruction::{
+    GovernanceInstruction, SetRealmAuthorityAction, Vote,
+};
+use crate::programs::governance::instruction_v2::GovernanceInstruction as GovernanceInstructionV2;
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "create-realm",
+    "deposit-governing-tokens",
+    "withdraw-governing-tokens",
+    "set-governance-delegate",
+    "create-governance",
+    "create-program-governance",
+    "create-proposal",
+    "add-signatory",
+    "remove-signatory",
+    "insert-transaction",
+    "remove-transaction",
+    "cancel-proposal",
+    "sign-off-proposal",
+    "cast-vote",
+    "finalize-vote",
+    "relinquish-vote",
+    "execute-transaction",
+    "create-mint-governance",
+    "create-token-governance",
+    "set-governance-config",
+    "flag-transaction-error",
+    "set-realm-authority",
+    "set-realm-config",
+    "create-token-owner-record",
+    "update-program-metadata",
+    "create-native-treasury",
+    "revoke-governing-tokens",
+    "refund-proposal-deposit",
+    "complete-proposal",
+    "add-required-signatory",
+    "remove-required-signatory",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = GovernanceInstruction::try_from_slice(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let governance_instruction = ui.clone();
+            match governance_instruction {
+                GovernanceInstruction::CreateRealm { name, config_args } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-realm".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "name".to_string(),
+                                value: name,
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "config_args".to_string(),
+                                value: serde_json::to_string(&config_args).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::DepositGoverningTokens { amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit-governing-tokens".to_string(),
+                            action: "governance.deposit".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::WithdrawGoverningTokens {} => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-governing-tokens".to_string(),
+                            action: "governance.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::SetGovernanceDelegate { new_governance_delegate } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-governance-delegate".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "new_governance_delegate".to_string(),
+                                value: if let Some(v) = new_governance_delegate {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::CreateGovernance { config } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-governance".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "config".to_string(),
+                                value: serde_json::to_string(&config).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::CreateProgramGovernance {
+                    config,
+                    transfer_upgrade_authority,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-program-governance".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "config".to_string(),
+                                value: serde_json::to_string(&config).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "transfer_upgrade_authority".to_string(),
+                                value: (transfer_upgrade_authority as i32).to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::CreateProposal {
+                    name,
+                    description_link,
+                    vote_type,
+                    options,
+                    use_deny_option,
+                    proposal_seed,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-proposal".to_string(),
+                            action: "governance.proposal.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "name".to_string(),
+                                value: name,
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "description_link".to_string(),
+                                value: description_link,
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "vote_type".to_string(),
+                                value: serde_json::to_string(&vote_type).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "options".to_string(),
+                                value: serde_json::to_string(&options).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "use_deny_option".to_string(),
+                                value: (use_deny_option as i32).to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "proposal_seed".to_string(),
+                                value: proposal_seed.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::AddSignatory { signatory } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "add-signatory".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "signatory".to_string(),
+                                value: signatory.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::Legacy1 => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "remove-signatory".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::InsertTransaction {
+                    option_index,
+                    index,
+                    hold_up_time,
+                    instructions,
+                } => {
+                    // The governed instructions are only summarised by the programs they invoke.
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "insert-transaction".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "option_index".to_string(),
+                                value: option_index.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "index".to_string(),
+                                value: index.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "hold_up_time".to_string(),
+                                value: hold_up_time.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "program_ids".to_string(),
+                                value: serde_json::to_string(
+                                    &instructions
+                                        .iter()
+                                        .map(|ix| ix.program_id.to_string())
+                                        .collect::<Vec<String>>(),
+                                )
+                                .unwrap()
+                                .to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::RemoveTransaction => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "remove-transaction".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::CancelProposal => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "cancel-proposal".to_string(),
+                            action: "governance.proposal.cancel".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::SignOffProposal => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "sign-off-proposal".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::CastVote { vote } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "cast-vote".to_string(),
+                            action: "governance.vote".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "vote_type".to_string(),
+                                value: match &vote {
+                                    Vote::Approve(_) => "approve".to_string(),
+                                    Vote::Deny => "deny".to_string(),
+                                    Vote::Abstain => "abstain".to_string(),
+                                    Vote::Veto => "veto".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "choices".to_string(),
+                                value: if let Vote::Approve(choices) = &vote {
+                                    serde_json::to_string(choices).unwrap().to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::FinalizeVote {} => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "finalize-vote".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::RelinquishVote {} => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "relinquish-vote".to_string(),
+                            action: "governance.vote.relinquish".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::ExecuteTransaction => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "execute-transaction".to_string(),
+                            action: "governance.proposal.execute".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::CreateMintGovernance {
+                    config,
+                    transfer_mint_authorities,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-mint-governance".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "config".to_string(),
+                                value: serde_json::to_string(&config).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "transfer_mint_authorities".to_string(),
+                                value: (transfer_mint_authorities as i32).to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::CreateTokenGovernance {
+                    config,
+                    transfer_account_authorities,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-token-governance".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "config".to_string(),
+                                value: serde_json::to_string(&config).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "transfer_account_authorities".to_string(),
+                                value: (transfer_account_authorities as i32).to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::SetGovernanceConfig { config } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-governance-config".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "config".to_string(),
+                                value: serde_json::to_string(&config).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::FlagTransactionError => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "flag-transaction-error".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::SetRealmAuthority { action } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-realm-authority".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "action".to_string(),
+                                value: match action {
+                                    SetRealmAuthorityAction::SetUnchecked => "set_unchecked".to_string(),
+                                    SetRealmAuthorityAction::SetChecked => "set_checked".to_string(),
+                                    SetRealmAuthorityAction::Remove => "remove".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::SetRealmConfig { config_args } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-realm-config".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "config_args".to_string(),
+                                value: serde_json::to_string(&config_args).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::CreateTokenOwnerRecord {} => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-token-owner-record".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::UpdateProgramMetadata {} => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "update-program-metadata".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::CreateNativeTreasury => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-native-treasury".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::RevokeGoverningTokens { amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "revoke-governing-tokens".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::RefundProposalDeposit {} => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "refund-proposal-deposit".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::CompleteProposal {} => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "complete-proposal".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                GovernanceInstruction::AddRequiredSignatory { signatory } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "add-required-signatory".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "signatory".to_string(),
+                                value: signatory.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                GovernanceInstruction::RemoveRequiredSignatory => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "remove-required-signatory".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            // Payloads written against v2.2 do not fit the v3.1 layout, try that one before
+            // giving up.
+            let legacy_result = GovernanceInstructionV2::try_from_slice(&instruction.data);
+            if let Ok(governance_instruction) = legacy_result {
+                return fragment_v2_instruction(&instruction, governance_instruction);
+            }
+
+            // If the instruction parsing is failing, bail out
+            error!("[spi-wrapper/programs/native_governance] Attempt to parse instruction from program {} \
+        failed due to {}.", instruction.program, err);
+
+            None
+        }
+    };
+}
+
+/// Extracts the properties of an instruction whose payload only fits the spl-governance v2.2
+/// layout. The remaining v2.2 instructions share their layout with v3.1 and never reach this.
+fn fragment_v2_instruction(
+    instruction: &Instruction,
+    governance_instruction: GovernanceInstructionV2,
+) -> Option<InstructionSet> {
+    match governance_instruction {
+        GovernanceInstructionV2::CreateRealm { name, config_args } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "create-realm".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "name".to_string(),
+                        value: name,
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "config_args".to_string(),
+                        value: serde_json::to_string(&config_args).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        GovernanceInstructionV2::CreateGovernance { config } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "create-governance".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "config".to_string(),
+                        value: serde_json::to_string(&config).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        GovernanceInstructionV2::CreateProgramGovernance {
+            config,
+            transfer_upgrade_authority,
+        } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "create-program-governance".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "config".to_string(),
+                        value: serde_json::to_string(&config).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "transfer_upgrade_authority".to_string(),
+                        value: (transfer_upgrade_authority as i32).to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        GovernanceInstructionV2::CreateProposal {
+            name,
+            description_link,
+            vote_type,
+            options,
+            use_deny_option,
+        } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "create-proposal".to_string(),
+                    action: "governance.proposal.create".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "name".to_string(),
+                        value: name,
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "description_link".to_string(),
+                        value: description_link,
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "vote_type".to_string(),
+                        value: serde_json::to_string(&vote_type).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "options".to_string(),
+                        value: serde_json::to_string(&options).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "use_deny_option".to_string(),
+                        value: (use_deny_option as i32).to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        GovernanceInstructionV2::RemoveSignatory { signatory } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "remove-signatory".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "signatory".to_string(),
+                        value: signatory.to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        GovernanceInstructionV2::CreateMintGovernance {
+            config,
+            transfer_mint_authorities,
+        } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "create-mint-governance".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "config".to_string(),
+                        value: serde_json::to_string(&config).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "transfer_mint_authorities".to_string(),
+                        value: (transfer_mint_authorities as i32).to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        GovernanceInstructionV2::CreateTokenGovernance {
+            config,
+            transfer_account_authorities,
+        } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "create-token-governance".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "config".to_string(),
+                        value: serde_json::to_string(&config).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "transfer_account_authorities".to_string(),
+                        value: (transfer_account_authorities as i32).to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        GovernanceInstructionV2::SetGovernanceConfig { config } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "set-governance-config".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "config".to_string(),
+                        value: serde_json::to_string(&config).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        GovernanceInstructionV2::SetRealmConfig { config_args } => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "set-realm-config".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "config_args".to_string(),
+                        value: serde_json::to_string(&config_args).unwrap().to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        _ => None,
+    }
+}
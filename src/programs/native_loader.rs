@@ -2,6 +2,9 @@ use crate::{Instruction, InstructionSet};
 
 pub const PROGRAM_ADDRESS: &str = "NativeLoader1111111111111111111111111111111";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[];
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -0,0 +1,55 @@
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS_V1: &str = "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo";
+pub const PROGRAM_ADDRESS: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "memo",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    // The whole instruction data is the memo. The program rejects invalid UTF-8, but failed
+    // transactions still get here, so fall back to a lossy conversion and flag it.
+    let is_valid_utf8 = std::str::from_utf8(&instruction.data).is_ok();
+    let memo = String::from_utf8_lossy(&instruction.data).to_string();
+
+    Some(InstructionSet {
+        function: InstructionFunction {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            program: instruction.program.clone(),
+            function_name: "memo".to_string(),
+            action: "memo.write".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        properties: vec![
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "memo".to_string(),
+                value: memo,
+                parent_key: "".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+            InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: "is_valid_utf8".to_string(),
+                value: (is_valid_utf8 as i32).to_string(),
+                parent_key: "".to_string(),
+                timestamp: instruction.timestamp.clone(),
+            },
+        ],
+    })
+}
@@ -0,0 +1,142 @@
+use borsh::BorshDeserialize;
+
+use crate::programs::account_compression::events::{
+    AccountCompressionEvent, ApplicationDataEvent, ChangeLogEvent,
+};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "change-log",
+    "application-data",
+    "noop",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    // The noop program accepts anything, the account compression program uses it to log its
+    // events. Anything else is kept as is.
+    let event_result = AccountCompressionEvent::try_from_slice(&instruction.data);
+
+    match event_result {
+        Ok(AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(change_log))) => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "change-log".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "tree_id".to_string(),
+                        value: change_log.id.to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "seq".to_string(),
+                        value: change_log.seq.to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "index".to_string(),
+                        value: change_log.index.to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "path".to_string(),
+                        value: serde_json::to_string(
+                            &change_log.path
+                                .iter()
+                                .map(|path_node| {
+                                    serde_json::json!({
+                                        "node": bs58::encode(path_node.node).into_string(),
+                                        "index": path_node.index,
+                                    })
+                                })
+                                .collect::<Vec<serde_json::Value>>(),
+                        )
+                        .unwrap()
+                        .to_string(),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        Ok(AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1(application_data))) => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "application-data".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "application_data".to_string(),
+                        value: base64::encode(&application_data.application_data),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+        Err(_) => {
+            Some(InstructionSet {
+                function: InstructionFunction {
+                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                    transaction_hash: instruction.transaction_hash.clone(),
+                    parent_index: instruction.parent_index.clone(),
+                    program: instruction.program.clone(),
+                    function_name: "noop".to_string(),
+                    action: "".to_string(),
+                    timestamp: instruction.timestamp.clone(),
+                },
+                properties: vec![
+                    InstructionProperty {
+                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                        transaction_hash: instruction.transaction_hash.clone(),
+                        parent_index: instruction.parent_index.clone(),
+                        key: "data".to_string(),
+                        value: base64::encode(&instruction.data),
+                        parent_key: "".to_string(),
+                        timestamp: instruction.timestamp.clone(),
+                    },
+                ],
+            })
+        }
+    }
+}
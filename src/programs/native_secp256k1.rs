@@ -11,6 +11,11 @@ use crate::{InstructionProperty, Instruction, InstructionSet, InstructionFunctio
 
 pub const PROGRAM_ADDRESS: &str = "KeccakSecp256k11111111111111111111111111111";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "verify-signatures",
+];
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -22,7 +27,7 @@ pub async fn fragment_instruction(
     instructions: &[CompiledInstruction]
 ) -> Option<InstructionSet> {
     // The first element within data slice tells us the number of signatures.
-    let count = instruction.data[0] as usize;
+    let count = *instruction.data.first()? as usize;
     let expected_data_size = 1 + count * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
 
     // Create the instruction
@@ -32,7 +37,8 @@ pub async fn fragment_instruction(
             transaction_hash: instruction.transaction_hash.clone(),
             parent_index: instruction.parent_index.clone(),
             program: instruction.program.clone(),
-            function_name: "".to_string(),
+            function_name: "verify-signatures".to_string(),
+            action: "".to_string(),
             timestamp: instruction.timestamp.clone(),
         },
         properties: vec![],
@@ -55,144 +61,198 @@ pub async fn fragment_instruction(
         .map(|instruction| instruction.data.as_ref())
         .collect();
 
+    // Every signature is reported. The ones that cannot be verified get a
+    // signatures/<i>/error property instead of their eth address and message hash.
     for i in 0..count {
         let start = 1 + i * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
         let end = start + SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let key_name = "signatures/".to_owned() + &*i.to_string();
 
         let offsets_result = bincode::deserialize::<SecpSignatureOffsets>(
             &instruction.data[start..end]);
+        let offsets = match offsets_result {
+            Ok(offsets) => offsets,
+            Err(_) => {
+                error!("{}",
+                    "[spi-wrapper/programs/native_secp256k1] FATAL: The signature offsets could \
+            not be read."
+                        .to_string(),
+                );
+                interpreted_instruction_set.properties
+                    .push(error_property(&instruction, &key_name, "invalid_signature_offsets"));
+                continue;
+            }
+        };
 
-        if let Ok(offsets) = offsets_result {
-            // Parse out signature
-            let signature_index = offsets.signature_instruction_index as usize;
-            if signature_index >= instruction_datas.len() {
-                info!(
-                    "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
+        // Parse out signature
+        let signature_index = offsets.signature_instruction_index as usize;
+        if signature_index >= instruction_datas.len() {
+            info!(
+                "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
         found because the signature_index was greater than the data size. -> \
         Secp256k1Error::InvalidInstructionDataSize"
-                );
-                return Some(interpreted_instruction_set)
-            }
-            let signature_instruction = instruction_datas[signature_index];
-            let sig_start = offsets.signature_offset as usize;
-            let sig_end = sig_start + SIGNATURE_SERIALIZED_SIZE;
-            if sig_end >= signature_instruction.len() {
-                info!(
-                    "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
+            );
+            interpreted_instruction_set.properties
+                .push(error_property(&instruction, &key_name, "invalid_instruction_data_size"));
+            continue;
+        }
+        let signature_instruction = instruction_datas[signature_index];
+        let sig_start = offsets.signature_offset as usize;
+        let sig_end = sig_start + SIGNATURE_SERIALIZED_SIZE;
+        if sig_end >= signature_instruction.len() {
+            info!(
+                "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
         found because the last_signature_index was greater than the data size. -> \
         Secp256k1Error::InvalidSignature"
-                );
-                return Some(interpreted_instruction_set)
-            }
-            let signature_result = libsecp256k1::Signature::parse_standard_slice(
-                &signature_instruction[sig_start..sig_end],
-            )
-                .map_err(|_| Secp256k1Error::InvalidSignature);
-            if let Err(_) = signature_result {
+            );
+            interpreted_instruction_set.properties
+                .push(error_property(&instruction, &key_name, "invalid_signature"));
+            continue;
+        }
+        let signature_result = libsecp256k1::Signature::parse_standard_slice(
+            &signature_instruction[sig_start..sig_end],
+        )
+            .map_err(|_| Secp256k1Error::InvalidSignature);
+        let signature = match signature_result {
+            Ok(signature) => signature,
+            Err(_) => {
                 info!(
                     "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
         found because the signature's slice cannot be parsed. -> Secp256k1Error::InvalidSignature"
                 );
-                return Some(interpreted_instruction_set)
+                interpreted_instruction_set.properties
+                    .push(error_property(&instruction, &key_name, "invalid_signature"));
+                continue;
             }
-            let signature = signature_result.unwrap();
+        };
 
-            let recovery_id_result =
-                libsecp256k1::RecoveryId::parse(signature_instruction[sig_end])
-                    .map_err(|_| Secp256k1Error::InvalidRecoveryId);
-            if let Err(_) = recovery_id_result {
+        let recovery_id_result =
+            libsecp256k1::RecoveryId::parse(signature_instruction[sig_end])
+                .map_err(|_| Secp256k1Error::InvalidRecoveryId);
+        let recovery_id = match recovery_id_result {
+            Ok(recovery_id) => recovery_id,
+            Err(_) => {
                 info!(
                     "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
-        found because the signature's slice cannot be parsed. -> Secp256k1Error::InvalidSignature"
+        found because the recovery id cannot be parsed. -> Secp256k1Error::InvalidRecoveryId"
                 );
-                return Some(interpreted_instruction_set)
+                interpreted_instruction_set.properties
+                    .push(error_property(&instruction, &key_name, "invalid_recovery_id"));
+                continue;
             }
-            let recovery_id = recovery_id_result.unwrap();
-
-            // Parse out pubkey
-            let eth_address_slice_result = get_data_slice(
-                &instruction_datas,
-                offsets.eth_address_instruction_index,
-                offsets.eth_address_offset,
-                HASHED_PUBKEY_SERIALIZED_SIZE,
-            );
-            if let Err(_) = eth_address_slice_result {
+        };
+
+        // Parse out pubkey
+        let eth_address_slice_result = get_data_slice(
+            &instruction_datas,
+            offsets.eth_address_instruction_index,
+            offsets.eth_address_offset,
+            HASHED_PUBKEY_SERIALIZED_SIZE,
+        );
+        let eth_address_slice = match eth_address_slice_result {
+            Ok(eth_address_slice) => eth_address_slice,
+            Err(_) => {
                 info!(
                     "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
         found because the eth address slice cannot be parsed."
                 );
-                return Some(interpreted_instruction_set)
+                interpreted_instruction_set.properties
+                    .push(error_property(&instruction, &key_name, "invalid_data_offsets"));
+                continue;
             }
-            let eth_address_slice = eth_address_slice_result.unwrap();
-
-            // Parse out message
-            let message_slice_result = get_data_slice(
-                &instruction_datas,
-                offsets.message_instruction_index,
-                offsets.message_data_offset,
-                offsets.message_data_size as usize,
-            );
-            if let Err(_) = message_slice_result {
+        };
+
+        // Parse out message
+        let message_slice_result = get_data_slice(
+            &instruction_datas,
+            offsets.message_instruction_index,
+            offsets.message_data_offset,
+            offsets.message_data_size as usize,
+        );
+        let message_slice = match message_slice_result {
+            Ok(message_slice) => message_slice,
+            Err(_) => {
                 info!(
                     "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
         found because the message slice cannot be parsed."
                 );
-                return Some(interpreted_instruction_set)
+                interpreted_instruction_set.properties
+                    .push(error_property(&instruction, &key_name, "invalid_data_offsets"));
+                continue;
             }
-            let message_slice = message_slice_result.unwrap();
-
-            let mut hasher = sha3::Keccak256::new();
-            hasher.update(message_slice);
-            let message_hash = hasher.finalize();
-
-            let pubkey_result: Result<PublicKey, Secp256k1Error> = libsecp256k1::recover(
-                &libsecp256k1::Message::parse_slice(&message_hash).unwrap(),
-                &signature,
-                &recovery_id,
-            )
-                .map_err(|_| Secp256k1Error::InvalidSignature);
-            if let Err(_) = pubkey_result {
+        };
+
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update(message_slice);
+        let message_hash = hasher.finalize();
+
+        let pubkey_result: Result<PublicKey, Secp256k1Error> = libsecp256k1::recover(
+            &libsecp256k1::Message::parse_slice(&message_hash).unwrap(),
+            &signature,
+            &recovery_id,
+        )
+            .map_err(|_| Secp256k1Error::InvalidSignature);
+        let pubkey: libsecp256k1::PublicKey = match pubkey_result {
+            Ok(pubkey) => pubkey,
+            Err(_) => {
                 info!(
                     "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
-        found because the pubkey's slice cannot be parsed. -> Secp256k1Error::InvalidSignature"
+        found because the pubkey cannot be recovered. -> Secp256k1Error::InvalidSignature"
                 );
-                return Some(interpreted_instruction_set)
+                interpreted_instruction_set.properties
+                    .push(error_property(&instruction, &key_name, "invalid_signature"));
+                continue;
             }
-            let pubkey: libsecp256k1::PublicKey = pubkey_result.unwrap();
+        };
 
-            let eth_address = construct_eth_pubkey(&pubkey);
-            let eth_address_str = "0x".to_string() + &*hex::encode(eth_address);
-            interpreted_instruction_set.properties
-                .push(InstructionProperty {
-                    tx_instruction_id: instruction.tx_instruction_id.clone(),
-                    transaction_hash: instruction.transaction_hash.clone(),
-                    parent_index: instruction.parent_index.clone(),
-                    key: "eth_address".to_string(),
-                    value: eth_address_str.to_string(),
-                    parent_key: "".to_string(),
-                    timestamp: instruction.timestamp.clone(),
-                });
-
-            if eth_address_slice != eth_address {
-                info!(
-                    "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
+        let eth_address = construct_eth_pubkey(&pubkey);
+        let eth_address_str = "0x".to_string() + &*hex::encode(eth_address);
+        interpreted_instruction_set.properties
+            .push(InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: key_name.clone() + &"/eth_address".to_owned(),
+                value: eth_address_str.to_string(),
+                parent_key: key_name.clone(),
+                timestamp: instruction.timestamp.clone(),
+            });
+        interpreted_instruction_set.properties
+            .push(InstructionProperty {
+                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                transaction_hash: instruction.transaction_hash.clone(),
+                parent_index: instruction.parent_index.clone(),
+                key: key_name.clone() + &"/message_hash".to_owned(),
+                value: "0x".to_string() + &*hex::encode(message_hash),
+                parent_key: key_name.clone(),
+                timestamp: instruction.timestamp.clone(),
+            });
+
+        if eth_address_slice != eth_address {
+            info!(
+                "[spi-wrapper/programs/native_secp256k1] INFO: On-chain failed instruction \
         found because there was an eth address slice mismatch v.s. the actual eth address. -> \
         Secp256k1Error::InvalidSignature"
-                );
-                return Some(interpreted_instruction_set)
-            }
-
-            return Some(interpreted_instruction_set)
+            );
+            interpreted_instruction_set.properties
+                .push(error_property(&instruction, &key_name, "eth_address_mismatch"));
         }
     }
 
-    error!("{}",
-        "[spi-wrapper/programs/native_secp256k1] FATAL: The instruction interpretation \
-    terminated unknowingly."
-            .to_string(),
-    );
+    Some(interpreted_instruction_set)
+}
 
-    None
+/// The property reporting why the signature under `key_name` could not be verified.
+fn error_property(instruction: &Instruction, key_name: &str, reason: &str) -> InstructionProperty {
+    InstructionProperty {
+        tx_instruction_id: instruction.tx_instruction_id.clone(),
+        transaction_hash: instruction.transaction_hash.clone(),
+        parent_index: instruction.parent_index.clone(),
+        key: key_name.to_owned() + "/error",
+        value: reason.to_string(),
+        parent_key: key_name.to_string(),
+        timestamp: instruction.timestamp.clone(),
+    }
 }
 
 // Adapted from secp256k1_instruction
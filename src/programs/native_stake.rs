@@ -8,6 +8,23 @@ use crate::{InstructionProperty, Instruction, InstructionSet, InstructionFunctio
 
 pub const PROGRAM_ADDRESS: &str = "Stake11111111111111111111111111111111111111";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize",
+    "initialize-checked",
+    "authorize",
+    "authorize-checked",
+    "authorize-checked-with-seed",
+    "authorize-with-seed",
+    "delegate-stake",
+    "split",
+    "merge",
+    "withdraw",
+    "deactivate",
+    "set-lockup",
+    "set-lockup-checked",
+];
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -36,6 +53,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -95,6 +113,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize-checked".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -139,6 +158,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "authorize".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -175,6 +195,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "authorize-checked".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -202,6 +223,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "authorize-checked-with-seed".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -281,6 +303,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "authorize-with-seed".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -348,6 +371,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "delegate-stake".to_string(),
+                            action: "stake.delegate".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -363,6 +387,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "split".to_string(),
+                            action: "stake.split".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -399,6 +424,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "merge".to_string(),
+                            action: "stake.merge".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -425,6 +451,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "withdraw".to_string(),
+                            action: "stake.withdraw".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -452,6 +479,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "deactivate".to_string(),
+                            action: "stake.deactivate".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -473,6 +501,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "set-lockup".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -527,6 +556,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "set-lockup-checked".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
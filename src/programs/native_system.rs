@@ -6,6 +6,22 @@ use crate::{InstructionProperty, Instruction, InstructionSet, InstructionFunctio
 
 pub const PROGRAM_ADDRESS: &str = "11111111111111111111111111111111";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "create-account",
+    "assign",
+    "transfer",
+    "create-account-with-seed",
+    "advance-nonce-account",
+    "withdraw-nonce-account",
+    "initialize-nonce-account",
+    "authorize-nonce-account",
+    "allocate",
+    "allocate-with-seed",
+    "assign-with-seed",
+    "transfer-with-seed",
+];
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -44,6 +60,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "create-account".to_string(),
+                            action: "system.account.create".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -93,6 +110,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "assign".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -126,6 +144,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "transfer".to_string(),
+                            action: "system.transfer".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -168,6 +187,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "create-account-with-seed".to_string(),
+                            action: "system.account.create".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -236,6 +256,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "advance-nonce-account".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -261,6 +282,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "withdraw-nonce-account".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -294,6 +316,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize-nonce-account".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -326,6 +349,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "authorize-nonce-account".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -357,6 +381,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "allocate".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -396,6 +421,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "allocate-with-seed".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -456,6 +482,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "assign-with-seed".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -513,6 +540,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "transfer-with-seed".to_string(),
+                            action: "system.transfer".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -7,6 +7,28 @@ use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSe
 
 pub const PROGRAM_ADDRESS: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize-mint",
+    "initialize-account",
+    "initialize-account-2",
+    "initialize-multisig",
+    "transfer",
+    "approve",
+    "revoke",
+    "set-authority",
+    "mint-to",
+    "burn",
+    "close-account",
+    "freeze-account",
+    "thaw-account",
+    "transfer-checked",
+    "approve-checked",
+    "mint-to-checked",
+    "burn-checked",
+    "sync-native",
+];
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -42,6 +64,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize-mint".to_string(),
+                            action: "token.mint.create".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -89,6 +112,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize-account".to_string(),
+                            action: "token.account.create".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
@@ -104,6 +128,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize-account-2".to_string(),
+                            action: "token.account.create".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -129,6 +154,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize-multisig".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -154,6 +180,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "transfer".to_string(),
+                            action: "token.transfer".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -179,6 +206,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "approve".to_string(),
+                            action: "token.approve".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -204,6 +232,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "revoke".to_string(),
+                            action: "token.revoke".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
@@ -227,6 +256,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "set-authority".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -265,6 +295,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "mint-to".to_string(),
+                            action: "token.mint".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -290,6 +321,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "burn".to_string(),
+                            action: "token.burn".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -315,6 +347,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "close-account".to_string(),
+                            action: "token.account.close".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
@@ -330,6 +363,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "freeze-account".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
@@ -345,6 +379,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "thaw-account".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
@@ -360,6 +395,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "transfer-checked".to_string(),
+                            action: "token.transfer".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -394,6 +430,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "approve-checked".to_string(),
+                            action: "token.approve".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -428,6 +465,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "mint-to-checked".to_string(),
+                            action: "token.mint".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -462,6 +500,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "burn-checked".to_string(),
+                            action: "token.burn".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -495,6 +534,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "sync-native".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
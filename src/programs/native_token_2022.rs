@@ -0,0 +1,1232 @@
+use solana_program::program_error::ProgramError;
+use solana_program::program_option::COption;
+use tracing::error;
+
+use crate::programs::native_token;
+use crate::programs::token_2022::extension::confidential_transfer::ConfidentialTransferInstruction;
+use crate::programs::token_2022::extension::transfer_fee::TransferFeeInstruction;
+use crate::programs::token_2022::instruction::TokenInstruction;
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize-mint",
+    "initialize-account",
+    "initialize-account-2",
+    "initialize-multisig",
+    "transfer",
+    "approve",
+    "revoke",
+    "set-authority",
+    "mint-to",
+    "burn",
+    "close-account",
+    "freeze-account",
+    "thaw-account",
+    "transfer-checked",
+    "approve-checked",
+    "mint-to-checked",
+    "burn-checked",
+    "sync-native",
+    "initialize-account-3",
+    "initialize-multisig-2",
+    "initialize-mint-2",
+    "get-account-data-size",
+    "initialize-immutable-owner",
+    "amount-to-ui-amount",
+    "ui-amount-to-amount",
+    "initialize-mint-close-authority",
+    "initialize-transfer-fee-config",
+    "transfer-checked-with-fee",
+    "withdraw-withheld-tokens-from-mint",
+    "withdraw-withheld-tokens-from-accounts",
+    "harvest-withheld-tokens-to-mint",
+    "set-transfer-fee",
+    "initialize-confidential-transfer-mint",
+    "update-confidential-transfer-mint",
+    "configure-confidential-transfer-account",
+    "approve-confidential-transfer-account",
+    "empty-confidential-transfer-account",
+    "confidential-transfer-deposit",
+    "confidential-transfer-withdraw",
+    "confidential-transfer",
+    "apply-pending-balance",
+    "enable-confidential-credits",
+    "disable-confidential-credits",
+    "enable-non-confidential-credits",
+    "disable-non-confidential-credits",
+    "confidential-transfer-with-split-proofs",
+    "default-account-state-extension",
+    "reallocate",
+    "memo-transfer-extension",
+    "create-native-mint",
+    "initialize-non-transferable-mint",
+    "interest-bearing-mint-extension",
+    "cpi-guard-extension",
+    "initialize-permanent-delegate",
+    "transfer-hook-extension",
+    "confidential-transfer-fee-extension",
+    "withdraw-excess-lamports",
+    "metadata-pointer-extension",
+];
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    // Token-2022 is a superset of SPL Token, instructions sharing the same layout are interpreted
+    // by the token module while keeping this program's address on the output.
+    if let Some(tag) = instruction.data.first() {
+        if TokenInstruction::is_shared_with_spl_token(*tag) {
+            return native_token::fragment_instruction(instruction).await;
+        }
+    }
+
+    let unpack_result = TokenInstruction::unpack(
+        instruction.data.as_slice());
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let token_instruction = ui.clone();
+            match token_instruction {
+                TokenInstruction::SetAuthority {
+                    authority_type,
+                    new_authority,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-authority".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "authority_type".to_string(),
+                                value: authority_type.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "new_authority".to_string(),
+                                value: if let COption::Some(pk) = new_authority {
+                                    pk.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::InitializeAccount3 { owner } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize-account-3".to_string(),
+                            action: "token.account.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "owner".to_string(),
+                                value: owner.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::InitializeMultisig2 { m } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize-multisig-2".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "m".to_string(),
+                                value: m.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::InitializeMint2 {
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize-mint-2".to_string(),
+                            action: "token.mint.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "decimals".to_string(),
+                                value: decimals.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "mint_authority".to_string(),
+                                value: mint_authority.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "freeze_authority".to_string(),
+                                value: if let COption::Some(pk) = freeze_authority {
+                                    pk.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::GetAccountDataSize { extension_types } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "get-account-data-size".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "extension_types".to_string(),
+                                value: serde_json::to_string(&extension_types).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::InitializeImmutableOwner => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize-immutable-owner".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                TokenInstruction::AmountToUiAmount { amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "amount-to-ui-amount".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::UiAmountToAmount { ui_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "ui-amount-to-amount".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "ui_amount".to_string(),
+                                value: ui_amount,
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::InitializeMintCloseAuthority { close_authority } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize-mint-close-authority".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "close_authority".to_string(),
+                                value: if let COption::Some(pk) = close_authority {
+                                    pk.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::TransferFeeExtension(transfer_fee_instruction) => {
+                    match transfer_fee_instruction {
+                        TransferFeeInstruction::InitializeTransferFeeConfig {
+                            transfer_fee_config_authority,
+                            withdraw_withheld_authority,
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "initialize-transfer-fee-config".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "transfer_fee_config_authority".to_string(),
+                                        value: if let COption::Some(pk) = transfer_fee_config_authority {
+                                            pk.to_string()
+                                        } else {
+                                            "".to_string()
+                                        },
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "withdraw_withheld_authority".to_string(),
+                                        value: if let COption::Some(pk) = withdraw_withheld_authority {
+                                            pk.to_string()
+                                        } else {
+                                            "".to_string()
+                                        },
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "transfer_fee_basis_points".to_string(),
+                                        value: transfer_fee_basis_points.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "maximum_fee".to_string(),
+                                        value: maximum_fee.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        TransferFeeInstruction::TransferCheckedWithFee { amount, decimals, fee } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "transfer-checked-with-fee".to_string(),
+                                    action: "token.transfer".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "amount".to_string(),
+                                        value: amount.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "decimals".to_string(),
+                                        value: decimals.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "fee".to_string(),
+                                        value: fee.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        TransferFeeInstruction::WithdrawWithheldTokensFromMint => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "withdraw-withheld-tokens-from-mint".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![],
+                            })
+                        }
+                        TransferFeeInstruction::WithdrawWithheldTokensFromAccounts { num_token_accounts } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "withdraw-withheld-tokens-from-accounts".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "num_token_accounts".to_string(),
+                                        value: num_token_accounts.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        TransferFeeInstruction::HarvestWithheldTokensToMint => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "harvest-withheld-tokens-to-mint".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![],
+                            })
+                        }
+                        TransferFeeInstruction::SetTransferFee {
+                            transfer_fee_basis_points,
+                            maximum_fee,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "set-transfer-fee".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "transfer_fee_basis_points".to_string(),
+                                        value: transfer_fee_basis_points.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "maximum_fee".to_string(),
+                                        value: maximum_fee.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                    }
+                }
+                TokenInstruction::ConfidentialTransferExtension(confidential_transfer_instruction) => {
+                    match confidential_transfer_instruction {
+                        ConfidentialTransferInstruction::InitializeMint {
+                            authority,
+                            auto_approve_new_accounts,
+                            auditor_elgamal_pubkey,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "initialize-confidential-transfer-mint".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "authority".to_string(),
+                                        value: if let COption::Some(pk) = authority {
+                                            pk.to_string()
+                                        } else {
+                                            "".to_string()
+                                        },
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "auto_approve_new_accounts".to_string(),
+                                        value: (auto_approve_new_accounts as i32).to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "auditor_elgamal_pubkey".to_string(),
+                                        value: if let Some(pk) = auditor_elgamal_pubkey {
+                                            base64::encode(pk)
+                                        } else {
+                                            "".to_string()
+                                        },
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        ConfidentialTransferInstruction::UpdateMint {
+                            auto_approve_new_accounts,
+                            auditor_elgamal_pubkey,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "update-confidential-transfer-mint".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "auto_approve_new_accounts".to_string(),
+                                        value: (auto_approve_new_accounts as i32).to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "auditor_elgamal_pubkey".to_string(),
+                                        value: if let Some(pk) = auditor_elgamal_pubkey {
+                                            base64::encode(pk)
+                                        } else {
+                                            "".to_string()
+                                        },
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        ConfidentialTransferInstruction::ConfigureAccount {
+                            maximum_pending_balance_credit_counter,
+                            proof_instruction_offset,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "configure-confidential-transfer-account".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "maximum_pending_balance_credit_counter".to_string(),
+                                        value: maximum_pending_balance_credit_counter.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "proof_instruction_offset".to_string(),
+                                        value: proof_instruction_offset.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        ConfidentialTransferInstruction::ApproveAccount => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "approve-confidential-transfer-account".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![],
+                            })
+                        }
+                        ConfidentialTransferInstruction::EmptyAccount { proof_instruction_offset } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "empty-confidential-transfer-account".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "proof_instruction_offset".to_string(),
+                                        value: proof_instruction_offset.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        ConfidentialTransferInstruction::Deposit {
+                            amount,
+                            decimals,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "confidential-transfer-deposit".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "amount".to_string(),
+                                        value: amount.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "decimals".to_string(),
+                                        value: decimals.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        ConfidentialTransferInstruction::Withdraw {
+                            amount,
+                            decimals,
+                            proof_instruction_offset,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "confidential-transfer-withdraw".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "amount".to_string(),
+                                        value: amount.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "decimals".to_string(),
+                                        value: decimals.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "proof_instruction_offset".to_string(),
+                                        value: proof_instruction_offset.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        ConfidentialTransferInstruction::Transfer { proof_instruction_offset } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "confidential-transfer".to_string(),
+                                    action: "token.transfer".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "proof_instruction_offset".to_string(),
+                                        value: proof_instruction_offset.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        ConfidentialTransferInstruction::ApplyPendingBalance {
+                            expected_pending_balance_credit_counter,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "apply-pending-balance".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "expected_pending_balance_credit_counter".to_string(),
+                                        value: expected_pending_balance_credit_counter.to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                        ConfidentialTransferInstruction::EnableConfidentialCredits => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "enable-confidential-credits".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![],
+                            })
+                        }
+                        ConfidentialTransferInstruction::DisableConfidentialCredits => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "disable-confidential-credits".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![],
+                            })
+                        }
+                        ConfidentialTransferInstruction::EnableNonConfidentialCredits => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "enable-non-confidential-credits".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![],
+                            })
+                        }
+                        ConfidentialTransferInstruction::DisableNonConfidentialCredits => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "disable-non-confidential-credits".to_string(),
+                                    action: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![],
+                            })
+                        }
+                        ConfidentialTransferInstruction::TransferWithSplitProofs {
+                            no_op_on_uninitialized_split_context_state,
+                            close_split_context_state_on_execution,
+                        } => {
+                            Some(InstructionSet {
+                                function: InstructionFunction {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    program: instruction.program.clone(),
+                                    function_name: "confidential-transfer-with-split-proofs".to_string(),
+                                    action: "token.transfer".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                                properties: vec![
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "no_op_on_uninitialized_split_context_state".to_string(),
+                                        value: (no_op_on_uninitialized_split_context_state as i32).to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                    InstructionProperty {
+                                        tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                        transaction_hash: instruction.transaction_hash.clone(),
+                                        parent_index: instruction.parent_index.clone(),
+                                        key: "close_split_context_state_on_execution".to_string(),
+                                        value: (close_split_context_state_on_execution as i32).to_string(),
+                                        parent_key: "".to_string(),
+                                        timestamp: instruction.timestamp.clone(),
+                                    },
+                                ],
+                            })
+                        }
+                    }
+                }
+                TokenInstruction::DefaultAccountStateExtension(instruction_type) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "default-account-state-extension".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "instruction_type".to_string(),
+                                value: instruction_type.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::Reallocate { extension_types } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "reallocate".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "extension_types".to_string(),
+                                value: serde_json::to_string(&extension_types).unwrap().to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::MemoTransferExtension(instruction_type) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "memo-transfer-extension".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "instruction_type".to_string(),
+                                value: instruction_type.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::CreateNativeMint => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-native-mint".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                TokenInstruction::InitializeNonTransferableMint => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize-non-transferable-mint".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                TokenInstruction::InterestBearingMintExtension(instruction_type) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "interest-bearing-mint-extension".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "instruction_type".to_string(),
+                                value: instruction_type.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::CpiGuardExtension(instruction_type) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "cpi-guard-extension".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "instruction_type".to_string(),
+                                value: instruction_type.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::InitializePermanentDelegate { delegate } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize-permanent-delegate".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "delegate".to_string(),
+                                value: delegate.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::TransferHookExtension(instruction_type) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "transfer-hook-extension".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "instruction_type".to_string(),
+                                value: instruction_type.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::ConfidentialTransferFeeExtension(instruction_type) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "confidential-transfer-fee-extension".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "instruction_type".to_string(),
+                                value: instruction_type.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TokenInstruction::WithdrawExcessLamports => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-excess-lamports".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                TokenInstruction::MetadataPointerExtension(instruction_type) => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "metadata-pointer-extension".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "instruction_type".to_string(),
+                                value: instruction_type.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/native_token_2022] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
@@ -7,6 +7,24 @@ use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSe
 
 pub const PROGRAM_ADDRESS: &str = "LendZqTs8gn5CTSJU1jWKhKuVpjJGom45nnwPb2AMTi";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "init-lending-market",
+    "set-lending-market-owner",
+    "init-reserve",
+    "refresh-reserve",
+    "deposit-reserve-liquidity",
+    "redeem-reserve-collateral",
+    "init-obligation",
+    "refresh-obligation",
+    "deposit-obligation-collateral",
+    "withdraw-obligation-collateral",
+    "borrow-obligation-liquidity",
+    "repay-obligation-liquidity",
+    "liquidate-obligation",
+    "flash-loan",
+];
+
 pub async fn fragment_instruction(
     instruction: Instruction
 ) -> Option<InstructionSet> {
@@ -29,6 +47,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "init-lending-market".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -61,6 +80,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "set-lending-market-owner".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -87,6 +107,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "init-reserve".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -191,6 +212,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "refresh-reserve".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
@@ -204,6 +226,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "deposit-reserve-liquidity".to_string(),
+                            action: "lending.deposit".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -227,6 +250,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "redeem-reserve-collateral".to_string(),
+                            action: "lending.withdraw".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -250,6 +274,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "init-obligation".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
@@ -263,6 +288,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "refresh-obligation".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![]
@@ -276,6 +302,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "deposit-obligation-collateral".to_string(),
+                            action: "lending.collateral.deposit".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -299,6 +326,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "withdraw-obligation-collateral".to_string(),
+                            action: "lending.collateral.withdraw".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -322,6 +350,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "borrow-obligation-liquidity".to_string(),
+                            action: "lending.borrow".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -345,6 +374,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "repay-obligation-liquidity".to_string(),
+                            action: "lending.repay".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -368,6 +398,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "liquidate-obligation".to_string(),
+                            action: "lending.liquidate".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -391,6 +422,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "flash-loan".to_string(),
+                            action: "lending.flashloan".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
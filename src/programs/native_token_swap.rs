@@ -7,6 +7,16 @@ use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSe
 
 pub const PROGRAM_ADDRESS: &str = "SwaPpA9LAaLfeLi3a68M4DjnLqgtticKg6CnyNwgAC8";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize",
+    "swap",
+    "deposit-all-token-types",
+    "withdraw-all-token-types",
+    "deposit-single-token-type-exact-amount-in",
+    "withdraw-single-token-type-exact-amount-out",
+];
+
 pub async fn fragment_instruction(
     // The instruction in question.
     instruction: Instruction
@@ -30,6 +40,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize".to_string(),
+                            action: "dex.pool.create".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -150,6 +161,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "swap".to_string(),
+                            action: "dex.swap".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -182,6 +194,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "deposit-all-token-types".to_string(),
+                            action: "dex.liquidity.add".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -223,6 +236,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "withdraw-all-token-types".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -264,6 +278,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "deposit-single-token-type-exact-amount-in".to_string(),
+                            action: "dex.liquidity.add".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
@@ -296,6 +311,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "withdraw-single-token-type-exact-amount-out".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
                             timestamp: instruction.timestamp.clone()
                         },
                         properties: vec![
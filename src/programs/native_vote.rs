@@ -8,6 +8,18 @@ use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSe
 
 pub const PROGRAM_ADDRESS: &str = "Vote111111111111111111111111111111111111111";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize-account",
+    "authorize",
+    "vote-authorize",
+    "update-validator-identity",
+    "update-commission",
+    "vote-switch",
+    "vote",
+    "withdraw",
+];
+
 /// Extracts the contents of an instruction into small bits and pieces, or what we would call,
 /// instruction_properties.
 ///
@@ -42,6 +54,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "initialize-account".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -99,6 +112,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "authorize".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -134,6 +148,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "vote-authorize".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -165,6 +180,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "update-validator-identity".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -179,6 +195,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "update-commission".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -202,6 +219,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "vote-switch".to_string(),
+                            action: "vote.cast".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -264,6 +282,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "vote".to_string(),
+                            action: "vote.cast".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -312,6 +331,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "withdraw".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
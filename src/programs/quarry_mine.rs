@@ -0,0 +1,343 @@
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "QMNeHCGYnLVDn1icRAfQZpjPLBNkfGbSKRB83G5d8KB";
+
+pub const CREATE_QUARRY: Discriminator = [18, 113, 223, 132, 105, 208, 102, 93];
+pub const CREATE_QUARRY_V2: Discriminator = [46, 84, 33, 67, 174, 252, 67, 148];
+pub const SET_ANNUAL_REWARDS: Discriminator = [135, 125, 92, 160, 100, 101, 141, 84];
+pub const SET_REWARDS_SHARE: Discriminator = [186, 168, 34, 15, 178, 135, 189, 129];
+pub const SET_FAMINE: Discriminator = [30, 50, 21, 169, 103, 68, 155, 192];
+pub const CREATE_MINER: Discriminator = [126, 23, 157, 1, 147, 94, 245, 69];
+pub const CREATE_MINER_V2: Discriminator = [177, 242, 29, 176, 13, 217, 36, 71];
+pub const STAKE_TOKENS: Discriminator = [136, 126, 91, 162, 40, 131, 13, 127];
+pub const WITHDRAW_TOKENS: Discriminator = [2, 4, 225, 61, 19, 182, 106, 170];
+pub const CLAIM_REWARDS: Discriminator = [4, 144, 132, 71, 116, 23, 151, 80];
+pub const CLAIM_REWARDS_V2: Discriminator = [69, 49, 158, 229, 212, 133, 136, 227];
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "create-quarry",
+    "create-quarry-v2",
+    "set-annual-rewards",
+    "set-rewards-share",
+    "set-famine",
+    "create-miner",
+    "create-miner-v2",
+    "stake-tokens",
+    "withdraw-tokens",
+    "claim-rewards",
+    "claim-rewards-v2",
+];
+
+/// Instructions of the Quarry mine program this decoder interprets. Rewarder administration and
+/// PDA bumps are left out.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuarryInstruction {
+    /// Create a quarry, paying rewards to the stakers of a token.
+    CreateQuarry,
+    /// Same as CreateQuarry, without the bump.
+    CreateQuarryV2,
+    /// Set the rewards the rewarder emits every year, shared between its quarries.
+    SetAnnualRewards { new_rate: u64 },
+    /// Set the share of the rewarder's emissions going to a quarry.
+    SetRewardsShare { new_share: u64 },
+    /// Stop the rewards of a quarry at the given time.
+    SetFamine { famine_ts: i64 },
+    /// Create the account tracking a user's stake in a quarry.
+    CreateMiner,
+    /// Same as CreateMiner, without the bump.
+    CreateMinerV2,
+    /// Stake tokens into a miner.
+    StakeTokens { amount: u64 },
+    /// Withdraw staked tokens from a miner.
+    WithdrawTokens { amount: u64 },
+    /// Claim the rewards of a miner.
+    ClaimRewards,
+    /// Same as ClaimRewards, with fewer accounts.
+    ClaimRewardsV2,
+}
+
+impl QuarryInstruction {
+    /// Unpacks a byte buffer into a QuarryInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            CREATE_QUARRY => Self::CreateQuarry,
+            CREATE_QUARRY_V2 => Self::CreateQuarryV2,
+            SET_ANNUAL_REWARDS => Self::SetAnnualRewards {
+                new_rate: unpack_args(rest)?,
+            },
+            SET_REWARDS_SHARE => Self::SetRewardsShare {
+                new_share: unpack_args(rest)?,
+            },
+            SET_FAMINE => Self::SetFamine {
+                famine_ts: unpack_args(rest)?,
+            },
+            CREATE_MINER => Self::CreateMiner,
+            CREATE_MINER_V2 => Self::CreateMinerV2,
+            STAKE_TOKENS => Self::StakeTokens {
+                amount: unpack_args(rest)?,
+            },
+            WITHDRAW_TOKENS => Self::WithdrawTokens {
+                amount: unpack_args(rest)?,
+            },
+            CLAIM_REWARDS => Self::ClaimRewards,
+            CLAIM_REWARDS_V2 => Self::ClaimRewardsV2,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = QuarryInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let quarry_instruction = ui.clone();
+            match quarry_instruction {
+                QuarryInstruction::CreateQuarry => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-quarry".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                QuarryInstruction::CreateQuarryV2 => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-quarry-v2".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                QuarryInstruction::SetAnnualRewards { new_rate } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-annual-rewards".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "new_rate".to_string(),
+                                value: new_rate.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                QuarryInstruction::SetRewardsShare { new_share } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-rewards-share".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "new_share".to_string(),
+                                value: new_share.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                QuarryInstruction::SetFamine { famine_ts } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-famine".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "famine_ts".to_string(),
+                                value: famine_ts.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                QuarryInstruction::CreateMiner => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-miner".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                QuarryInstruction::CreateMinerV2 => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-miner-v2".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                QuarryInstruction::StakeTokens { amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "stake-tokens".to_string(),
+                            action: "farm.deposit".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                QuarryInstruction::WithdrawTokens { amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-tokens".to_string(),
+                            action: "farm.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                QuarryInstruction::ClaimRewards => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "claim-rewards".to_string(),
+                            action: "farm.harvest".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                QuarryInstruction::ClaimRewardsV2 => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "claim-rewards-v2".to_string(),
+                            action: "farm.harvest".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/quarry_mine] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
@@ -0,0 +1,631 @@
+use std::convert::TryInto;
+
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize",
+    "initialize2",
+    "deposit",
+    "withdraw",
+    "migrate-to-open-book",
+    "withdraw-pnl",
+    "swap-base-in",
+    "pre-initialize",
+    "swap-base-out",
+];
+
+/// Instructions of the Raydium AMM v4 program this decoder interprets, packed little endian
+/// behind a one byte tag. Admin and monitoring instructions are not covered.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AmmInstruction {
+    /// Initializes a new AMM pool.
+    Initialize { nonce: u8, open_time: u64 },
+    /// Initializes a new AMM pool along with its OpenBook market and initial liquidity.
+    Initialize2 {
+        nonce: u8,
+        open_time: u64,
+        init_pc_amount: u64,
+        init_coin_amount: u64,
+    },
+    /// Deposit some tokens into the pool. `base_side` 0 fixes the coin amount, 1 the pc amount.
+    /// Older clients do not send `other_amount_min`.
+    Deposit {
+        max_coin_amount: u64,
+        max_pc_amount: u64,
+        base_side: u64,
+        other_amount_min: Option<u64>,
+    },
+    /// Withdraw the vault tokens from the pool at the current ratio. Older clients do not send
+    /// the minimum amounts.
+    Withdraw {
+        amount: u64,
+        min_coin_amount: Option<u64>,
+        min_pc_amount: Option<u64>,
+    },
+    /// Migrate the pool from Serum to OpenBook.
+    MigrateToOpenBook,
+    /// Withdraw the pnl from the pool, only the pnl owner can do it.
+    WithdrawPnl,
+    /// Swap coin or pc from the pool, with an exact amount in.
+    SwapBaseIn { amount_in: u64, minimum_amount_out: u64 },
+    /// Prepares the accounts of a new pool.
+    PreInitialize { nonce: u8 },
+    /// Swap coin or pc from the pool, with an exact amount out.
+    SwapBaseOut { max_amount_in: u64, amount_out: u64 },
+}
+
+impl AmmInstruction {
+    /// Unpacks a byte buffer into an AmmInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match tag {
+            0 => {
+                let (nonce, rest) = Self::unpack_u8(rest)?;
+                let (open_time, _rest) = Self::unpack_u64(rest)?;
+                Self::Initialize { nonce, open_time }
+            }
+            1 => {
+                let (nonce, rest) = Self::unpack_u8(rest)?;
+                let (open_time, rest) = Self::unpack_u64(rest)?;
+                let (init_pc_amount, rest) = Self::unpack_u64(rest)?;
+                let (init_coin_amount, _rest) = Self::unpack_u64(rest)?;
+                Self::Initialize2 {
+                    nonce,
+                    open_time,
+                    init_pc_amount,
+                    init_coin_amount,
+                }
+            }
+            3 => {
+                let (max_coin_amount, rest) = Self::unpack_u64(rest)?;
+                let (max_pc_amount, rest) = Self::unpack_u64(rest)?;
+                let (base_side, rest) = Self::unpack_u64(rest)?;
+                let other_amount_min = Self::unpack_u64(rest).ok().map(|(amount, _rest)| amount);
+                Self::Deposit {
+                    max_coin_amount,
+                    max_pc_amount,
+                    base_side,
+                    other_amount_min,
+                }
+            }
+            4 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                // The minimum amounts are sent together or not at all
+                let (min_coin_amount, min_pc_amount) = match Self::unpack_u64(rest) {
+                    Ok((min_coin_amount, rest)) => {
+                        let (min_pc_amount, _rest) = Self::unpack_u64(rest)?;
+                        (Some(min_coin_amount), Some(min_pc_amount))
+                    }
+                    Err(_) => (None, None),
+                };
+                Self::Withdraw {
+                    amount,
+                    min_coin_amount,
+                    min_pc_amount,
+                }
+            }
+            5 => Self::MigrateToOpenBook,
+            7 => Self::WithdrawPnl,
+            9 => {
+                let (amount_in, rest) = Self::unpack_u64(rest)?;
+                let (minimum_amount_out, _rest) = Self::unpack_u64(rest)?;
+                Self::SwapBaseIn {
+                    amount_in,
+                    minimum_amount_out,
+                }
+            }
+            10 => Self::PreInitialize {
+                nonce: Self::unpack_u8(rest)?.0,
+            },
+            11 => {
+                let (max_amount_in, rest) = Self::unpack_u64(rest)?;
+                let (amount_out, _rest) = Self::unpack_u64(rest)?;
+                Self::SwapBaseOut {
+                    max_amount_in,
+                    amount_out,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    fn unpack_u8(input: &[u8]) -> Result<(u8, &[u8]), ProgramError> {
+        let (&value, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok((value, rest))
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
+        let value = input
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok((value, &input[8..]))
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = AmmInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let amm_instruction = ui.clone();
+            match amm_instruction {
+                AmmInstruction::Initialize { nonce, open_time } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize".to_string(),
+                            action: "dex.pool.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "nonce".to_string(),
+                                value: nonce.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "open_time".to_string(),
+                                value: open_time.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::Initialize2 {
+                    nonce,
+                    open_time,
+                    init_pc_amount,
+                    init_coin_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize2".to_string(),
+                            action: "dex.pool.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "nonce".to_string(),
+                                value: nonce.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "open_time".to_string(),
+                                value: open_time.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "init_pc_amount".to_string(),
+                                value: init_pc_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "init_coin_amount".to_string(),
+                                value: init_coin_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::Deposit {
+                    max_coin_amount,
+                    max_pc_amount,
+                    base_side,
+                    other_amount_min,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit".to_string(),
+                            action: "dex.liquidity.add".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "max_coin_amount".to_string(),
+                                value: max_coin_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "max_pc_amount".to_string(),
+                                value: max_pc_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "base_side".to_string(),
+                                value: match base_side {
+                                    0 => "coin".to_string(),
+                                    1 => "pc".to_string(),
+                                    _ => base_side.to_string(),
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "other_amount_min".to_string(),
+                                value: if let Some(v) = other_amount_min {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::Withdraw { amount, min_coin_amount, min_pc_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "min_coin_amount".to_string(),
+                                value: if let Some(v) = min_coin_amount {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "min_pc_amount".to_string(),
+                                value: if let Some(v) = min_pc_amount {
+                                    v.to_string()
+                                } else {
+                                    "".to_string()
+                                },
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::MigrateToOpenBook => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "migrate-to-open-book".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                AmmInstruction::WithdrawPnl => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-pnl".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                AmmInstruction::SwapBaseIn { amount_in, minimum_amount_out } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "swap-base-in".to_string(),
+                            action: "dex.swap".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_in".to_string(),
+                                value: amount_in.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_amount_out".to_string(),
+                                value: minimum_amount_out.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::PreInitialize { nonce } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "pre-initialize".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "nonce".to_string(),
+                                value: nonce.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                AmmInstruction::SwapBaseOut { max_amount_in, amount_out } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "swap-base-out".to_string(),
+                            action: "dex.swap".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "max_amount_in".to_string(),
+                                value: max_amount_in.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_out".to_string(),
+                                value: amount_out.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/raydium_amm] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pack(tag: u8, values: &[u64]) -> Vec<u8> {
+        let mut data = vec![tag];
+        for value in values {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_unpack_deposit() {
+        assert_eq!(
+            AmmInstruction::unpack(&pack(3, &[100, 200, 0, 190])).unwrap(),
+            AmmInstruction::Deposit {
+                max_coin_amount: 100,
+                max_pc_amount: 200,
+                base_side: 0,
+                other_amount_min: Some(190),
+            }
+        );
+
+        // Older clients stop after the base side.
+        assert_eq!(
+            AmmInstruction::unpack(&pack(3, &[100, 200, 1])).unwrap(),
+            AmmInstruction::Deposit {
+                max_coin_amount: 100,
+                max_pc_amount: 200,
+                base_side: 1,
+                other_amount_min: None,
+            }
+        );
+
+        assert!(AmmInstruction::unpack(&pack(3, &[100, 200])).is_err());
+    }
+
+    #[test]
+    fn test_unpack_withdraw() {
+        assert_eq!(
+            AmmInstruction::unpack(&pack(4, &[50, 10, 20])).unwrap(),
+            AmmInstruction::Withdraw {
+                amount: 50,
+                min_coin_amount: Some(10),
+                min_pc_amount: Some(20),
+            }
+        );
+
+        // Older clients only send the amount.
+        assert_eq!(
+            AmmInstruction::unpack(&pack(4, &[50])).unwrap(),
+            AmmInstruction::Withdraw {
+                amount: 50,
+                min_coin_amount: None,
+                min_pc_amount: None,
+            }
+        );
+
+        // The minimum amounts come in pairs.
+        assert!(AmmInstruction::unpack(&pack(4, &[50, 10])).is_err());
+    }
+
+    #[test]
+    fn test_unpack_swaps() {
+        assert_eq!(
+            AmmInstruction::unpack(&pack(9, &[1_000, 990])).unwrap(),
+            AmmInstruction::SwapBaseIn {
+                amount_in: 1_000,
+                minimum_amount_out: 990,
+            }
+        );
+        assert_eq!(
+            AmmInstruction::unpack(&pack(11, &[1_010, 1_000])).unwrap(),
+            AmmInstruction::SwapBaseOut {
+                max_amount_in: 1_010,
+                amount_out: 1_000,
+            }
+        );
+        assert!(AmmInstruction::unpack(&pack(9, &[1_000])).is_err());
+    }
+
+    #[test]
+    fn test_unpack_initialize2() {
+        let mut data = vec![1, 254];
+        for value in &[1_700_000_000u64, 5_000, 1_000_000] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        assert_eq!(
+            AmmInstruction::unpack(&data).unwrap(),
+            AmmInstruction::Initialize2 {
+                nonce: 254,
+                open_time: 1_700_000_000,
+                init_pc_amount: 5_000,
+                init_coin_amount: 1_000_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_unknown_tag() {
+        assert_eq!(
+            AmmInstruction::unpack(&[2]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(AmmInstruction::unpack(&[]), Err(ProgramError::InvalidInstructionData));
+    }
+}
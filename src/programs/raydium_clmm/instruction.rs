@@ -0,0 +1,229 @@
+//! Instruction types
+
+use solana_program::program_error::ProgramError;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+
+pub const CREATE_POOL: Discriminator = [233, 146, 209, 142, 207, 104, 64, 188];
+pub const OPEN_POSITION: Discriminator = [135, 128, 47, 77, 15, 152, 240, 49];
+pub const OPEN_POSITION_V2: Discriminator = [77, 184, 74, 214, 112, 86, 241, 199];
+pub const CLOSE_POSITION: Discriminator = [123, 134, 81, 0, 49, 68, 98, 98];
+pub const INCREASE_LIQUIDITY: Discriminator = [46, 156, 243, 118, 13, 205, 251, 178];
+pub const INCREASE_LIQUIDITY_V2: Discriminator = [133, 29, 89, 223, 69, 238, 176, 10];
+pub const DECREASE_LIQUIDITY: Discriminator = [160, 38, 208, 111, 104, 91, 44, 1];
+pub const DECREASE_LIQUIDITY_V2: Discriminator = [58, 127, 188, 62, 79, 82, 196, 96];
+pub const SWAP: Discriminator = [248, 198, 158, 145, 225, 117, 135, 200];
+pub const SWAP_V2: Discriminator = [43, 4, 237, 11, 26, 201, 30, 98];
+pub const SWAP_ROUTER_BASE_IN: Discriminator = [69, 125, 115, 218, 245, 186, 242, 196];
+
+/// Instructions supported by the Raydium concentrated liquidity program.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AmmV3Instruction {
+    /// Creates a pool for the given token pair and the initial price.
+    ///
+    /// * `sqrt_price_x64` - the initial sqrt price (amount_token_1 / amount_token_0) of the pool
+    /// as a Q64.64
+    CreatePool { sqrt_price_x64: u128, open_time: u64 },
+
+    /// Creates a new position wrapped in a NFT.
+    OpenPosition {
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        tick_array_lower_start_index: i32,
+        tick_array_upper_start_index: i32,
+        liquidity: u128,
+        amount_0_max: u64,
+        amount_1_max: u64,
+    },
+
+    /// Creates a new position wrapped in a Token-2022 NFT.
+    OpenPositionV2 {
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+        tick_array_lower_start_index: i32,
+        tick_array_upper_start_index: i32,
+        liquidity: u128,
+        amount_0_max: u64,
+        amount_1_max: u64,
+        with_metadata: bool,
+        base_flag: Option<bool>,
+    },
+
+    /// Close a position, the nft mint and nft account.
+    ClosePosition,
+
+    /// Increases liquidity with a exist position, with amount paid by `payer`.
+    IncreaseLiquidity {
+        liquidity: u128,
+        amount_0_max: u64,
+        amount_1_max: u64,
+    },
+
+    /// Increases liquidity with a exist position, supporting Token-2022 mints.
+    IncreaseLiquidityV2 {
+        liquidity: u128,
+        amount_0_max: u64,
+        amount_1_max: u64,
+        base_flag: Option<bool>,
+    },
+
+    /// Decreases liquidity with a exist position. Decreasing zero liquidity collects the fees
+    /// and rewards owed to the position.
+    DecreaseLiquidity {
+        liquidity: u128,
+        amount_0_min: u64,
+        amount_1_min: u64,
+    },
+
+    /// Decreases liquidity with a exist position, supporting Token-2022 mints.
+    DecreaseLiquidityV2 {
+        liquidity: u128,
+        amount_0_min: u64,
+        amount_1_min: u64,
+    },
+
+    /// Swaps one token for as much as possible of another token across a single pool.
+    Swap {
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit_x64: u128,
+        is_base_input: bool,
+    },
+
+    /// Swaps one token for as much as possible of another token across a single pool, supporting
+    /// Token-2022 mints.
+    SwapV2 {
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit_x64: u128,
+        is_base_input: bool,
+    },
+
+    /// Swap token for as much as possible of another token across the path provided, base input.
+    SwapRouterBaseIn {
+        amount_in: u64,
+        amount_out_minimum: u64,
+    },
+}
+
+impl AmmV3Instruction {
+    /// Unpacks a byte buffer into an AmmV3Instruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            CREATE_POOL => {
+                let (sqrt_price_x64, open_time) = unpack_args(rest)?;
+                Self::CreatePool {
+                    sqrt_price_x64,
+                    open_time,
+                }
+            }
+            OPEN_POSITION => {
+                let (
+                    tick_lower_index,
+                    tick_upper_index,
+                    tick_array_lower_start_index,
+                    tick_array_upper_start_index,
+                    liquidity,
+                    amount_0_max,
+                    amount_1_max,
+                ) = unpack_args(rest)?;
+                Self::OpenPosition {
+                    tick_lower_index,
+                    tick_upper_index,
+                    tick_array_lower_start_index,
+                    tick_array_upper_start_index,
+                    liquidity,
+                    amount_0_max,
+                    amount_1_max,
+                }
+            }
+            OPEN_POSITION_V2 => {
+                let (
+                    tick_lower_index,
+                    tick_upper_index,
+                    tick_array_lower_start_index,
+                    tick_array_upper_start_index,
+                    liquidity,
+                    amount_0_max,
+                    amount_1_max,
+                    with_metadata,
+                    base_flag,
+                ) = unpack_args(rest)?;
+                Self::OpenPositionV2 {
+                    tick_lower_index,
+                    tick_upper_index,
+                    tick_array_lower_start_index,
+                    tick_array_upper_start_index,
+                    liquidity,
+                    amount_0_max,
+                    amount_1_max,
+                    with_metadata,
+                    base_flag,
+                }
+            }
+            CLOSE_POSITION => Self::ClosePosition,
+            INCREASE_LIQUIDITY => {
+                let (liquidity, amount_0_max, amount_1_max) = unpack_args(rest)?;
+                Self::IncreaseLiquidity {
+                    liquidity,
+                    amount_0_max,
+                    amount_1_max,
+                }
+            }
+            INCREASE_LIQUIDITY_V2 => {
+                let (liquidity, amount_0_max, amount_1_max, base_flag) = unpack_args(rest)?;
+                Self::IncreaseLiquidityV2 {
+                    liquidity,
+                    amount_0_max,
+                    amount_1_max,
+                    base_flag,
+                }
+            }
+            DECREASE_LIQUIDITY => {
+                let (liquidity, amount_0_min, amount_1_min) = unpack_args(rest)?;
+                Self::DecreaseLiquidity {
+                    liquidity,
+                    amount_0_min,
+                    amount_1_min,
+                }
+            }
+            DECREASE_LIQUIDITY_V2 => {
+                let (liquidity, amount_0_min, amount_1_min) = unpack_args(rest)?;
+                Self::DecreaseLiquidityV2 {
+                    liquidity,
+                    amount_0_min,
+                    amount_1_min,
+                }
+            }
+            SWAP => {
+                let (amount, other_amount_threshold, sqrt_price_limit_x64, is_base_input) =
+                    unpack_args(rest)?;
+                Self::Swap {
+                    amount,
+                    other_amount_threshold,
+                    sqrt_price_limit_x64,
+                    is_base_input,
+                }
+            }
+            SWAP_V2 => {
+                let (amount, other_amount_threshold, sqrt_price_limit_x64, is_base_input) =
+                    unpack_args(rest)?;
+                Self::SwapV2 {
+                    amount,
+                    other_amount_threshold,
+                    sqrt_price_limit_x64,
+                    is_base_input,
+                }
+            }
+            SWAP_ROUTER_BASE_IN => {
+                let (amount_in, amount_out_minimum) = unpack_args(rest)?;
+                Self::SwapRouterBaseIn {
+                    amount_in,
+                    amount_out_minimum,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
@@ -0,0 +1,212 @@
+use std::convert::TryInto;
+
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS_V3: &str = "EhhTKczWMGQt46ynNeRX1WfeagwwJd7ufHvCDjRxjo5Q";
+pub const PROGRAM_ADDRESS_V5: &str = "9KEPoZmtHUrBbhWN1v1KWLMkkvwY6WLtAVUCPRtRjP4z";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "deposit",
+    "harvest",
+    "withdraw",
+    "create-associated-ledger-account",
+];
+
+/// Instructions of the Raydium farm programs this decoder interprets, a one byte tag followed by
+/// the little endian arguments. Both versions share the layouts but not the tags.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FarmInstruction {
+    /// Stake LP tokens and claim the pending rewards.
+    Deposit { amount: u64 },
+    /// Unstake LP tokens and claim the pending rewards.
+    Withdraw { amount: u64 },
+    /// Create the ledger account tracking a user's stake.
+    CreateAssociatedLedgerAccount,
+}
+
+impl FarmInstruction {
+    /// Unpacks a byte buffer sent to the given farm program into a FarmInstruction
+    pub fn unpack(program: &str, input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match (program, tag) {
+            (PROGRAM_ADDRESS_V3, 1) | (PROGRAM_ADDRESS_V5, 11) => Self::Deposit {
+                amount: Self::unpack_u64(rest)?,
+            },
+            (PROGRAM_ADDRESS_V3, 2) | (PROGRAM_ADDRESS_V5, 12) => Self::Withdraw {
+                amount: Self::unpack_u64(rest)?,
+            },
+            (PROGRAM_ADDRESS_V3, 9) | (PROGRAM_ADDRESS_V5, 10) => {
+                Self::CreateAssociatedLedgerAccount
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<u64, ProgramError> {
+        input
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = FarmInstruction::unpack(&instruction.program, &instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let farm_instruction = ui.clone();
+            match farm_instruction {
+                FarmInstruction::Deposit { amount } => {
+                    if amount == 0 {
+                        // Depositing nothing only claims the pending rewards
+                        Some(InstructionSet {
+                            function: InstructionFunction {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                program: instruction.program.clone(),
+                                function_name: "harvest".to_string(),
+                                action: "farm.harvest".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            properties: vec![],
+                        })
+                    } else {
+                        Some(InstructionSet {
+                            function: InstructionFunction {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                program: instruction.program.clone(),
+                                function_name: "deposit".to_string(),
+                                action: "farm.deposit".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            properties: vec![
+                                InstructionProperty {
+                                    tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                    transaction_hash: instruction.transaction_hash.clone(),
+                                    parent_index: instruction.parent_index.clone(),
+                                    key: "amount".to_string(),
+                                    value: amount.to_string(),
+                                    parent_key: "".to_string(),
+                                    timestamp: instruction.timestamp.clone(),
+                                },
+                            ],
+                        })
+                    }
+                }
+                FarmInstruction::Withdraw { amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw".to_string(),
+                            action: "farm.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount".to_string(),
+                                value: amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                FarmInstruction::CreateAssociatedLedgerAccount => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "create-associated-ledger-account".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/raydium_farm] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unpack_per_version() {
+        let mut deposit_v3 = vec![1];
+        deposit_v3.extend_from_slice(&7u64.to_le_bytes());
+        let mut deposit_v5 = deposit_v3.clone();
+        deposit_v5[0] = 11;
+
+        assert_eq!(
+            FarmInstruction::unpack(PROGRAM_ADDRESS_V3, &deposit_v3).unwrap(),
+            FarmInstruction::Deposit { amount: 7 }
+        );
+        assert_eq!(
+            FarmInstruction::unpack(PROGRAM_ADDRESS_V5, &deposit_v5).unwrap(),
+            FarmInstruction::Deposit { amount: 7 }
+        );
+        assert!(FarmInstruction::unpack(PROGRAM_ADDRESS_V3, &deposit_v5).is_err());
+        assert!(FarmInstruction::unpack(PROGRAM_ADDRESS_V5, &deposit_v3).is_err());
+
+        assert_eq!(
+            FarmInstruction::unpack(PROGRAM_ADDRESS_V3, &[9]).unwrap(),
+            FarmInstruction::CreateAssociatedLedgerAccount
+        );
+        assert!(FarmInstruction::unpack(PROGRAM_ADDRESS_V3, &[10]).is_err());
+    }
+}
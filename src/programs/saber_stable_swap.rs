@@ -0,0 +1,606 @@
+use std::convert::TryInto;
+
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "SSwpkEEcbUqx4vtoEByFjSkhKdCT862DNVb52nZg1UZ";
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize",
+    "swap",
+    "deposit",
+    "withdraw",
+    "withdraw-one",
+    "ramp-a",
+    "stop-ramp-a",
+    "pause",
+    "unpause",
+    "set-fee-account",
+    "apply-new-admin",
+    "commit-new-admin",
+    "set-new-fees",
+];
+
+/// The fees charged by a pool, as fractions. The admin fees are taken out of the trade and
+/// withdraw fees.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fees {
+    pub admin_trade_fee_numerator: u64,
+    pub admin_trade_fee_denominator: u64,
+    pub admin_withdraw_fee_numerator: u64,
+    pub admin_withdraw_fee_denominator: u64,
+    pub trade_fee_numerator: u64,
+    pub trade_fee_denominator: u64,
+    pub withdraw_fee_numerator: u64,
+    pub withdraw_fee_denominator: u64,
+}
+
+/// Instructions of the Saber stable swap program, a one byte tag followed by the little endian
+/// arguments. The admin instructions are tagged from 100.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SwapInstruction {
+    /// Initializes a new pool.
+    Initialize { nonce: u8, amp_factor: u64, fees: Fees },
+    /// Swap the tokens in the pool.
+    Swap {
+        amount_in: u64,
+        minimum_amount_out: u64,
+    },
+    /// Deposit both tokens in exchange for pool tokens.
+    Deposit {
+        token_a_amount: u64,
+        token_b_amount: u64,
+        min_mint_amount: u64,
+    },
+    /// Burn pool tokens in exchange for both tokens.
+    Withdraw {
+        pool_token_amount: u64,
+        minimum_token_a_amount: u64,
+        minimum_token_b_amount: u64,
+    },
+    /// Burn pool tokens in exchange for one of the tokens.
+    WithdrawOne {
+        pool_token_amount: u64,
+        minimum_token_amount: u64,
+    },
+    /// Ramp the amplification coefficient towards `target_amp` until `stop_ramp_ts`.
+    RampA { target_amp: u64, stop_ramp_ts: i64 },
+    /// Stop ramping the amplification coefficient.
+    StopRampA,
+    /// Pause the pool.
+    Pause,
+    /// Unpause the pool.
+    Unpause,
+    /// Set the account collecting the admin fees.
+    SetFeeAccount,
+    /// Apply the admin committed by CommitNewAdmin.
+    ApplyNewAdmin,
+    /// Commit a new admin, to be applied after a delay.
+    CommitNewAdmin,
+    /// Update the fees of the pool.
+    SetNewFees { fees: Fees },
+}
+
+impl SwapInstruction {
+    /// Unpacks a byte buffer into a SwapInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match tag {
+            0 => {
+                let (&nonce, rest) = rest
+                    .split_first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Self::Initialize {
+                    nonce,
+                    amp_factor: Self::unpack_u64(rest, 0)?,
+                    fees: Self::unpack_fees(
+                        rest.get(8..).ok_or(ProgramError::InvalidInstructionData)?,
+                    )?,
+                }
+            }
+            1 => Self::Swap {
+                amount_in: Self::unpack_u64(rest, 0)?,
+                minimum_amount_out: Self::unpack_u64(rest, 8)?,
+            },
+            2 => Self::Deposit {
+                token_a_amount: Self::unpack_u64(rest, 0)?,
+                token_b_amount: Self::unpack_u64(rest, 8)?,
+                min_mint_amount: Self::unpack_u64(rest, 16)?,
+            },
+            3 => Self::Withdraw {
+                pool_token_amount: Self::unpack_u64(rest, 0)?,
+                minimum_token_a_amount: Self::unpack_u64(rest, 8)?,
+                minimum_token_b_amount: Self::unpack_u64(rest, 16)?,
+            },
+            4 => Self::WithdrawOne {
+                pool_token_amount: Self::unpack_u64(rest, 0)?,
+                minimum_token_amount: Self::unpack_u64(rest, 8)?,
+            },
+            100 => Self::RampA {
+                target_amp: Self::unpack_u64(rest, 0)?,
+                stop_ramp_ts: Self::unpack_u64(rest, 8)? as i64,
+            },
+            101 => Self::StopRampA,
+            102 => Self::Pause,
+            103 => Self::Unpause,
+            104 => Self::SetFeeAccount,
+            105 => Self::ApplyNewAdmin,
+            106 => Self::CommitNewAdmin,
+            107 => Self::SetNewFees {
+                fees: Self::unpack_fees(rest)?,
+            },
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    fn unpack_fees(input: &[u8]) -> Result<Fees, ProgramError> {
+        Ok(Fees {
+            admin_trade_fee_numerator: Self::unpack_u64(input, 0)?,
+            admin_trade_fee_denominator: Self::unpack_u64(input, 8)?,
+            admin_withdraw_fee_numerator: Self::unpack_u64(input, 16)?,
+            admin_withdraw_fee_denominator: Self::unpack_u64(input, 24)?,
+            trade_fee_numerator: Self::unpack_u64(input, 32)?,
+            trade_fee_denominator: Self::unpack_u64(input, 40)?,
+            withdraw_fee_numerator: Self::unpack_u64(input, 48)?,
+            withdraw_fee_denominator: Self::unpack_u64(input, 56)?,
+        })
+    }
+
+    fn unpack_u64(input: &[u8], offset: usize) -> Result<u64, ProgramError> {
+        input
+            .get(offset..offset + 8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = SwapInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let swap_instruction = ui.clone();
+            match swap_instruction {
+                SwapInstruction::Initialize { nonce, amp_factor, fees } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "nonce".to_string(),
+                            value: nonce.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "amp_factor".to_string(),
+                            value: amp_factor.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(fees_properties(&instruction, &fees));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "initialize".to_string(),
+                            action: "dex.pool.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                SwapInstruction::Swap { amount_in, minimum_amount_out } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "swap".to_string(),
+                            action: "dex.swap".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "amount_in".to_string(),
+                                value: amount_in.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_amount_out".to_string(),
+                                value: minimum_amount_out.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                SwapInstruction::Deposit { token_a_amount, token_b_amount, min_mint_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit".to_string(),
+                            action: "dex.liquidity.add".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_a_amount".to_string(),
+                                value: token_a_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "token_b_amount".to_string(),
+                                value: token_b_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "min_mint_amount".to_string(),
+                                value: min_mint_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                SwapInstruction::Withdraw {
+                    pool_token_amount,
+                    minimum_token_a_amount,
+                    minimum_token_b_amount,
+                } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "pool_token_amount".to_string(),
+                                value: pool_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_token_a_amount".to_string(),
+                                value: minimum_token_a_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_token_b_amount".to_string(),
+                                value: minimum_token_b_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                SwapInstruction::WithdrawOne { pool_token_amount, minimum_token_amount } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-one".to_string(),
+                            action: "dex.liquidity.remove".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "pool_token_amount".to_string(),
+                                value: pool_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "minimum_token_amount".to_string(),
+                                value: minimum_token_amount.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                SwapInstruction::RampA { target_amp, stop_ramp_ts } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "ramp-a".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "target_amp".to_string(),
+                                value: target_amp.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "stop_ramp_ts".to_string(),
+                                value: stop_ramp_ts.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                SwapInstruction::StopRampA => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "stop-ramp-a".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                SwapInstruction::Pause => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "pause".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                SwapInstruction::Unpause => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "unpause".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                SwapInstruction::SetFeeAccount => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-fee-account".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                SwapInstruction::ApplyNewAdmin => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "apply-new-admin".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                SwapInstruction::CommitNewAdmin => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "commit-new-admin".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                SwapInstruction::SetNewFees { fees } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "set-new-fees".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: fees_properties(&instruction, &fees),
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/saber_stable_swap] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
+
+/// The properties of the fees of a pool, under "fees".
+fn fees_properties(instruction: &Instruction, fees: &Fees) -> Vec<InstructionProperty> {
+    vec![
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "admin_trade_fee_numerator".to_string(),
+            value: fees.admin_trade_fee_numerator.to_string(),
+            parent_key: "fees".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "admin_trade_fee_denominator".to_string(),
+            value: fees.admin_trade_fee_denominator.to_string(),
+            parent_key: "fees".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "admin_withdraw_fee_numerator".to_string(),
+            value: fees.admin_withdraw_fee_numerator.to_string(),
+            parent_key: "fees".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "admin_withdraw_fee_denominator".to_string(),
+            value: fees.admin_withdraw_fee_denominator.to_string(),
+            parent_key: "fees".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "trade_fee_numerator".to_string(),
+            value: fees.trade_fee_numerator.to_string(),
+            parent_key: "fees".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "trade_fee_denominator".to_string(),
+            value: fees.trade_fee_denominator.to_string(),
+            parent_key: "fees".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "withdraw_fee_numerator".to_string(),
+            value: fees.withdraw_fee_numerator.to_string(),
+            parent_key: "fees".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: "withdraw_fee_denominator".to_string(),
+            value: fees.withdraw_fee_denominator.to_string(),
+            parent_key: "fees".to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+    ]
+}
@@ -7,6 +7,27 @@ pub const PROGRAM_ADDRESS_V1: &str = "BJ3jrUzddfuSrZHXSCxMUUQsjKEyLmuuyZebkcaFp2
 pub const PROGRAM_ADDRESS_V2: &str = "EUqojwWA2rd19FZrzeBncJsm38Jm1hEhE3zsmX3bRc2o";
 pub const PROGRAM_ADDRESS_V3: &str = "9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "initialize-market",
+    "new-order",
+    "match-orders",
+    "consume-events",
+    "cancel-order",
+    "settle-funds",
+    "cancel-order-by-client-id",
+    "disable-market",
+    "sweep-fees",
+    "new-order-v2",
+    "new-order-v3",
+    "cancel-order-v2",
+    "cancel-order-by-client-id-v2",
+    "send-take",
+    "close-open-orders",
+    "init-open-orders",
+    "prune",
+];
+
 pub async fn fragment_instruction(
     instruction: Instruction
 ) -> Option<InstructionSet> {
@@ -34,6 +55,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "initialize-market".to_string(),
+                        action: "dex.market.create".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -103,6 +125,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "new-order".to_string(),
+                        action: "dex.order.place".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -169,6 +192,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "match-orders".to_string(),
+                        action: "dex.order.match".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -197,6 +221,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "consume-events".to_string(),
+                        action: "dex.order.match".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -235,6 +260,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "cancel-order".to_string(),
+                        action: "dex.order.cancel".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -286,6 +312,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "settle-funds".to_string(),
+                        action: "dex.order.settle".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![],
@@ -303,6 +330,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "cancel-order-by-client-id".to_string(),
+                        action: "dex.order.cancel".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -328,6 +356,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "disable-market".to_string(),
+                        action: "".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![],
@@ -347,6 +376,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "sweep-fees".to_string(),
+                        action: "".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![],
@@ -370,6 +400,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "new-order-v2".to_string(),
+                        action: "dex.order.place".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -465,6 +496,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "new-order-v3".to_string(),
+                        action: "dex.order.place".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -571,6 +603,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "cancel-order-v2".to_string(),
+                        action: "dex.order.cancel".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -613,6 +646,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "cancel-order-by-client-id-v2".to_string(),
+                        action: "dex.order.cancel".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -641,6 +675,7 @@ pub async fn fragment_instruction(
                         parent_index: instruction.parent_index.clone(),
                         program: instruction.program.clone(),
                         function_name: "send-take".to_string(),
+                        action: "dex.order.place".to_string(),
                         timestamp: instruction.timestamp.clone(),
                     },
                     properties: vec![
@@ -723,6 +758,7 @@ pub async fn fragment_instruction(
                         program: instruction.program.clone(),
                         timestamp: instruction.timestamp.clone(),
                         function_name: "close-open-orders".to_string(),
+                        action: "".to_string(),
                     },
                     properties: vec![],
                 })
@@ -740,6 +776,7 @@ pub async fn fragment_instruction(
                         program: instruction.program.clone(),
                         timestamp: instruction.timestamp.clone(),
                         function_name: "init-open-orders".to_string(),
+                        action: "".to_string(),
                     },
                     properties: vec![],
                 })
@@ -753,6 +790,7 @@ pub async fn fragment_instruction(
                         program: instruction.program.clone(),
                         timestamp: instruction.timestamp.clone(),
                         function_name: "prune".to_string(),
+                        action: "".to_string(),
                     },
                     properties: vec![
                         InstructionProperty {
@@ -7,6 +7,27 @@ use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSe
 
 pub const PROGRAM_ADDRESS: &str = "So1endDq2YkqhipRh3WViPa8hdiSpxWy6z3Z6tMCpAo";
 
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "init-lending-market",
+    "set-lending-market-owner",
+    "init-reserve",
+    "refresh-reserve",
+    "deposit-reserve-liquidity",
+    "redeem-reserve-collateral",
+    "init-obligation",
+    "refresh-obligation",
+    "deposit-obligation-collateral",
+    "withdraw-obligation-collateral",
+    "borrow-obligation-liquidity",
+    "repay-obligation-liquidity",
+    "liquidate-obligation",
+    "flash-loan",
+    "deposit-reserve-liquidity-and-obligation-collateral",
+    "withdraw-obligation-collateral-and-redeem-reserve-collateral",
+    "update-reserve-config",
+];
+
 pub async fn fragment_instruction(
     instruction: Instruction
 ) -> Option<InstructionSet> {
@@ -29,6 +50,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "init-lending-market".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -61,6 +83,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "set-lending-market-owner".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -87,6 +110,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "init-reserve".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -191,6 +215,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "refresh-reserve".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -204,6 +229,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "deposit-reserve-liquidity".to_string(),
+                            action: "lending.deposit".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -227,6 +253,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "redeem-reserve-collateral".to_string(),
+                            action: "lending.withdraw".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -250,6 +277,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "init-obligation".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -263,6 +291,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "refresh-obligation".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![],
@@ -276,6 +305,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "deposit-obligation-collateral".to_string(),
+                            action: "lending.collateral.deposit".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -299,6 +329,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "withdraw-obligation-collateral".to_string(),
+                            action: "lending.collateral.withdraw".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -322,6 +353,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "borrow-obligation-liquidity".to_string(),
+                            action: "lending.borrow".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -345,6 +377,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "repay-obligation-liquidity".to_string(),
+                            action: "lending.repay".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -368,6 +401,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "liquidate-obligation".to_string(),
+                            action: "lending.liquidate".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -391,6 +425,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "flash-loan".to_string(),
+                            action: "lending.flashloan".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -416,6 +451,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "deposit-reserve-liquidity-and-obligation-collateral".to_string(),
+                            action: "lending.deposit".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -441,6 +477,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "withdraw-obligation-collateral-and-redeem-reserve-collateral".to_string(),
+                            action: "lending.withdraw".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
@@ -464,6 +501,7 @@ pub async fn fragment_instruction(
                             parent_index: instruction.parent_index.clone(),
                             program: instruction.program.clone(),
                             function_name: "update-reserve-config".to_string(),
+                            action: "".to_string(),
                             timestamp: instruction.timestamp.clone(),
                         },
                         properties: vec![
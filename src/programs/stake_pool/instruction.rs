@@ -0,0 +1,364 @@
+//! Instruction types
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+// The tag of `AddValidatorToPool`, whose argument was added in v0.7.0.
+const ADD_VALIDATOR_TO_POOL: u8 = 1;
+
+/// Fee rate as a ratio, minted on `UpdateStakePoolBalance` as a proportion of
+/// the rewards
+/// If either the numerator or the denominator is 0, the fee is considered to be 0
+#[derive(Clone, Copy, Debug, Default, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct Fee {
+    /// denominator of the fee ratio
+    pub denominator: u64,
+    /// numerator of the fee ratio
+    pub numerator: u64,
+}
+
+/// The type of fees that can be set on the stake pool
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum FeeType {
+    /// Referral fees for SOL deposits
+    SolReferral(u8),
+    /// Referral fees for stake deposits
+    StakeReferral(u8),
+    /// Management fee paid per epoch
+    Epoch(Fee),
+    /// Stake withdrawal fee
+    StakeWithdrawal(Fee),
+    /// Deposit fee for SOL deposits
+    SolDeposit(Fee),
+    /// Deposit fee for stake deposits
+    StakeDeposit(Fee),
+    /// SOL withdrawal fee
+    SolWithdrawal(Fee),
+}
+
+/// Defines which validator vote account is set during the
+/// `SetPreferredValidator` instruction
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum PreferredValidatorType {
+    /// Set preferred validator for deposits
+    Deposit,
+    /// Set preferred validator for withdraws
+    Withdraw,
+}
+
+/// Defines which authority to update in the `SetFundingAuthority`
+/// instruction
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum FundingType {
+    /// Sets the stake deposit authority
+    StakeDeposit,
+    /// Sets the SOL deposit authority
+    SolDeposit,
+    /// Sets the SOL withdraw authority
+    SolWithdraw,
+}
+
+/// Instructions supported by the StakePool program.
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum StakePoolInstruction {
+    ///   Initializes a new StakePool.
+    ///
+    ///   0. `[w]` New StakePool to create.
+    ///   1. `[s]` Manager
+    ///   2. `[]` Staker
+    ///   3. `[]` Stake pool withdraw authority
+    ///   4. `[w]` Uninitialized validator stake list storage account
+    ///   5. `[]` Reserve stake account must be initialized, have zero balance,
+    ///      and staker / withdrawer authority set to pool withdraw authority.
+    ///   6. `[]` Pool token mint. Must have zero supply, owned by withdraw authority.
+    ///   7. `[]` Pool account to deposit the generated fee for manager.
+    ///   8. `[]` Token program id
+    ///   9. `[]` (Optional) Deposit authority that must sign all deposits.
+    Initialize {
+        /// Fee assessed as percentage of perceived rewards
+        fee: Fee,
+        /// Fee charged per withdrawal as percentage of withdrawal
+        withdrawal_fee: Fee,
+        /// Fee charged per deposit as percentage of deposit
+        deposit_fee: Fee,
+        /// Percentage [0-100] of deposit_fee that goes to referrer
+        referral_fee: u8,
+        /// Maximum expected number of validators
+        max_validators: u32,
+    },
+
+    ///   (Staker only) Adds stake account delegated to validator to the pool's
+    ///   list of managed validators.
+    ///
+    ///   userdata: optional non-zero u32 seed used for generating the validator
+    ///   stake address
+    AddValidatorToPool(u32),
+
+    ///   (Staker only) Removes validator from the pool, deactivating its stake
+    RemoveValidatorFromPool,
+
+    /// (Staker only) Decrease active stake on a validator, eventually moving it to the reserve
+    DecreaseValidatorStake {
+        /// amount of lamports to split into the transient stake account
+        lamports: u64,
+        /// seed used to create transient stake account
+        transient_stake_seed: u64,
+    },
+
+    /// (Staker only) Increase stake on a validator from the reserve account
+    IncreaseValidatorStake {
+        /// amount of lamports to increase on the given validator
+        lamports: u64,
+        /// seed used to create transient stake account
+        transient_stake_seed: u64,
+    },
+
+    /// (Staker only) Set the preferred deposit or withdraw stake account for the
+    /// stake pool
+    SetPreferredValidator {
+        /// Affected operation (deposit or withdraw)
+        validator_type: PreferredValidatorType,
+        /// Validator vote account that deposits or withdraws must go through,
+        /// unset with None
+        validator_vote_address: Option<Pubkey>,
+    },
+
+    ///  Updates balances of validator and transient stake accounts in the pool
+    UpdateValidatorListBalance {
+        /// Index to start updating on the validator list
+        start_index: u32,
+        /// If true, don't try merging transient stake accounts into the reserve or
+        /// validator stake account.  Useful for testing or if a particular stake
+        /// account is in a bad state, but we still want to update
+        no_merge: bool,
+    },
+
+    ///   Updates total pool balance based on balances in the reserve and validator list
+    UpdateStakePoolBalance,
+
+    ///   Cleans up validator stake account entries marked as `ReadyForRemoval`
+    CleanupRemovedValidatorEntries,
+
+    ///   Deposit some stake into the pool. The output is a "pool" token
+    ///   representing ownership into the pool. Inputs are converted to the
+    ///   current ratio.
+    DepositStake,
+
+    ///   Withdraw the token from the pool at the current ratio.
+    ///
+    ///   userdata: amount of pool tokens to withdraw
+    WithdrawStake(u64),
+
+    ///  (Manager only) Update manager
+    SetManager,
+
+    ///  (Manager only) Update fee
+    SetFee {
+        /// Type of fee to update and value to update it to
+        fee: FeeType,
+    },
+
+    ///  (Manager or staker only) Update staker
+    SetStaker,
+
+    ///   Deposit SOL directly into the pool's reserve account. The output is a "pool" token
+    ///   representing ownership into the pool. Inputs are converted to the current ratio.
+    DepositSol(u64),
+
+    ///  (Manager only) Update SOL deposit, stake deposit, or SOL withdrawal authority.
+    SetFundingAuthority(FundingType),
+
+    ///   Withdraw SOL directly from the pool's reserve account. Fails if the
+    ///   reserve does not have enough SOL.
+    WithdrawSol(u64),
+
+    /// Create token metadata for the stake-pool token in the
+    /// metaplex-token program
+    CreateTokenMetadata {
+        /// Token name
+        name: String,
+        /// Token symbol e.g. stkSOL
+        symbol: String,
+        /// URI of the uploaded metadata of the spl-token
+        uri: String,
+    },
+
+    /// Update token metadata for the stake-pool token in the
+    /// metaplex-token program
+    UpdateTokenMetadata {
+        /// Token name
+        name: String,
+        /// Token symbol e.g. stkSOL
+        symbol: String,
+        /// URI of the uploaded metadata of the spl-token
+        uri: String,
+    },
+
+    /// (Staker only) Increase stake on a validator again in an epoch.
+    IncreaseAdditionalValidatorStake {
+        /// amount of lamports to increase on the given validator
+        lamports: u64,
+        /// seed used to create transient stake account
+        transient_stake_seed: u64,
+        /// seed used to create ephemeral account.
+        ephemeral_stake_seed: u64,
+    },
+
+    /// (Staker only) Decrease active stake again from a validator, eventually
+    /// moving it to the reserve
+    DecreaseAdditionalValidatorStake {
+        /// amount of lamports to split into the transient stake account
+        lamports: u64,
+        /// seed used to create transient stake account
+        transient_stake_seed: u64,
+        /// seed used to create ephemeral account.
+        ephemeral_stake_seed: u64,
+    },
+
+    /// (Staker only) Decrease active stake on a validator, eventually moving it
+    /// to the reserve
+    DecreaseValidatorStakeWithReserve {
+        /// amount of lamports to split into the transient stake account
+        lamports: u64,
+        /// seed used to create transient stake account
+        transient_stake_seed: u64,
+    },
+
+    /// (Staker only) Redelegate active stake on a validator, eventually moving it to another
+    Redelegate {
+        /// Amount of lamports to redelegate
+        lamports: u64,
+        /// Seed used to create source transient stake account
+        source_transient_stake_seed: u64,
+        /// Seed used to create destination ephemeral account.
+        ephemeral_stake_seed: u64,
+        /// Seed used to create destination transient stake account. If there is
+        /// already transient stake, this must match the current seed, otherwise
+        /// it can be anything
+        destination_transient_stake_seed: u64,
+    },
+
+    ///   Deposit some stake into the pool, with a specified slippage constraint.
+    DepositStakeWithSlippage {
+        /// Minimum amount of pool tokens that must be received
+        minimum_pool_tokens_out: u64,
+    },
+
+    ///   Withdraw the token from the pool at the current ratio, specifying a
+    ///   minimum expected output lamport amount.
+    WithdrawStakeWithSlippage {
+        /// Pool tokens to burn in exchange for lamports
+        pool_tokens_in: u64,
+        /// Minimum amount of lamports that must be received
+        minimum_lamports_out: u64,
+    },
+
+    ///   Deposit SOL directly into the pool's reserve account, with a specified
+    ///   slippage constraint.
+    DepositSolWithSlippage {
+        /// Amount of lamports to deposit into the reserve
+        lamports_in: u64,
+        /// Minimum amount of pool tokens that must be received
+        minimum_pool_tokens_out: u64,
+    },
+
+    ///   Withdraw SOL directly from the pool's reserve account, with a specified
+    ///   slippage constraint.
+    WithdrawSolWithSlippage {
+        /// Pool tokens to burn in exchange for lamports
+        pool_tokens_in: u64,
+        /// Minimum amount of lamports that must be received
+        minimum_lamports_out: u64,
+    },
+}
+
+impl StakePoolInstruction {
+    /// Unpacks a byte buffer into a StakePoolInstruction. Before v0.7.0 `AddValidatorToPool`
+    /// carried no seed, such payloads are read with the seed of 0, which means no seed.
+    pub fn unpack(input: &[u8]) -> Result<Self, std::io::Error> {
+        if input == [ADD_VALIDATOR_TO_POOL] {
+            return Ok(Self::AddValidatorToPool(0));
+        }
+
+        Self::try_from_slice(input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unpack_initialize() {
+        let mut data = vec![0];
+        for value in &[10_000u64, 300, 0, 0, 10_000, 10] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.push(50);
+        data.extend_from_slice(&2_950u32.to_le_bytes());
+        assert_eq!(
+            StakePoolInstruction::unpack(&data).unwrap(),
+            StakePoolInstruction::Initialize {
+                fee: Fee {
+                    denominator: 10_000,
+                    numerator: 300,
+                },
+                withdrawal_fee: Fee::default(),
+                deposit_fee: Fee {
+                    denominator: 10_000,
+                    numerator: 10,
+                },
+                referral_fee: 50,
+                max_validators: 2_950,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_add_validator_to_pool() {
+        let mut data = vec![ADD_VALIDATOR_TO_POOL];
+        data.extend_from_slice(&7u32.to_le_bytes());
+        assert_eq!(
+            StakePoolInstruction::unpack(&data).unwrap(),
+            StakePoolInstruction::AddValidatorToPool(7)
+        );
+
+        // The payload before v0.7.0, without the seed.
+        assert_eq!(
+            StakePoolInstruction::unpack(&[ADD_VALIDATOR_TO_POOL]).unwrap(),
+            StakePoolInstruction::AddValidatorToPool(0)
+        );
+    }
+
+    #[test]
+    fn test_unpack_set_fee() {
+        let mut data = vec![12, 2];
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        assert_eq!(
+            StakePoolInstruction::unpack(&data).unwrap(),
+            StakePoolInstruction::SetFee {
+                fee: FeeType::Epoch(Fee {
+                    denominator: 100,
+                    numerator: 1,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_deposit_sol_with_slippage() {
+        let mut data = vec![25];
+        data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&990_000_000u64.to_le_bytes());
+        assert_eq!(
+            StakePoolInstruction::unpack(&data).unwrap(),
+            StakePoolInstruction::DepositSolWithSlippage {
+                lamports_in: 1_000_000_000,
+                minimum_pool_tokens_out: 990_000_000,
+            }
+        );
+
+        assert!(StakePoolInstruction::unpack(&data[..9]).is_err());
+    }
+}
@@ -0,0 +1,5 @@
+//! Vendored stake-pool definitions, trimmed to what is needed to interpret its instructions.
+//!
+//! The layouts mirror spl-stake-pool v1.0.0.
+
+pub mod instruction;
@@ -0,0 +1,592 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+use tracing::error;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+use crate::{Instruction, InstructionFunction, InstructionProperty, InstructionSet};
+
+pub const PROGRAM_ADDRESS: &str = "TSWAPaqyCSx2KABk68Shruf4rp7CxcNi8hAsbdwmHbN";
+
+pub const INIT_POOL: Discriminator = [116, 233, 199, 204, 115, 159, 171, 36];
+pub const CLOSE_POOL: Discriminator = [140, 189, 209, 23, 239, 62, 239, 11];
+pub const EDIT_POOL: Discriminator = [50, 174, 34, 36, 3, 166, 29, 204];
+pub const DEPOSIT_NFT: Discriminator = [93, 226, 132, 166, 141, 9, 48, 101];
+pub const WITHDRAW_NFT: Discriminator = [142, 181, 191, 149, 82, 175, 216, 100];
+pub const DEPOSIT_SOL: Discriminator = [108, 81, 78, 117, 125, 155, 56, 200];
+pub const WITHDRAW_SOL: Discriminator = [145, 131, 74, 136, 65, 137, 42, 38];
+pub const BUY_NFT: Discriminator = [96, 0, 28, 190, 49, 107, 83, 222];
+pub const SELL_NFT_TOKEN_POOL: Discriminator = [57, 44, 192, 48, 83, 8, 107, 48];
+pub const SELL_NFT_TRADE_POOL: Discriminator = [131, 82, 125, 77, 13, 157, 36, 90];
+pub const LIST: Discriminator = [54, 174, 193, 67, 17, 41, 132, 38];
+pub const DELIST: Discriminator = [55, 136, 205, 107, 107, 173, 4, 31];
+pub const EDIT_SINGLE_LISTING: Discriminator = [88, 38, 236, 212, 31, 185, 18, 166];
+pub const BUY_SINGLE_LISTING: Discriminator = [245, 220, 105, 73, 117, 98, 78, 141];
+
+// The function names this decoder may emit.
+pub const FUNCTIONS: &[&str] = &[
+    "init-pool",
+    "close-pool",
+    "edit-pool",
+    "deposit-nft",
+    "withdraw-nft",
+    "deposit-sol",
+    "withdraw-sol",
+    "buy-nft",
+    "sell-nft-token-pool",
+    "sell-nft-trade-pool",
+    "list",
+    "delist",
+    "edit-single-listing",
+    "buy-single-listing",
+];
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum PoolType {
+    Token,
+    Nft,
+    Trade,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum CurveType {
+    Linear,
+    Exponential,
+}
+
+/// The pricing configuration of a pool, which also identifies it.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct PoolConfig {
+    pub pool_type: PoolType,
+    pub curve_type: CurveType,
+    pub starting_price: u64,
+    pub delta: u64,
+    pub mm_compound_fees: bool,
+    pub mm_fee_bps: Option<u16>,
+}
+
+/// Instructions of the Tensorswap program this decoder interprets, following its published IDL.
+/// Only the leading arguments are read, the trailing ones (cosigning, royalties, authorization
+/// data) are left unread.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TensorswapInstruction {
+    /// Create a pool.
+    InitPool { config: PoolConfig },
+    /// Close an empty pool.
+    ClosePool { config: PoolConfig },
+    /// Change the configuration of a pool.
+    EditPool {
+        old_config: PoolConfig,
+        new_config: PoolConfig,
+    },
+    /// Deposit an NFT into an NFT or trade pool.
+    DepositNft { config: PoolConfig },
+    /// Withdraw an NFT from an NFT or trade pool.
+    WithdrawNft { config: PoolConfig },
+    /// Deposit SOL into a token or trade pool.
+    DepositSol { config: PoolConfig, lamports: u64 },
+    /// Withdraw SOL from a token or trade pool.
+    WithdrawSol { config: PoolConfig, lamports: u64 },
+    /// Buy an NFT from a pool.
+    BuyNft { config: PoolConfig, max_price: u64 },
+    /// Sell an NFT into a token pool.
+    SellNftTokenPool { config: PoolConfig, min_price: u64 },
+    /// Sell an NFT into a trade pool.
+    SellNftTradePool { config: PoolConfig, min_price: u64 },
+    /// List a single NFT.
+    List { price: u64 },
+    /// Remove a single listing.
+    Delist,
+    /// Change the price of a single listing.
+    EditSingleListing { price: u64 },
+    /// Buy a single listing.
+    BuySingleListing { max_price: u64 },
+}
+
+impl TensorswapInstruction {
+    /// Unpacks a byte buffer into a TensorswapInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            INIT_POOL => Self::InitPool {
+                config: unpack_args(rest)?,
+            },
+            CLOSE_POOL => Self::ClosePool {
+                config: unpack_args(rest)?,
+            },
+            EDIT_POOL => {
+                let (old_config, new_config) = unpack_args(rest)?;
+                Self::EditPool {
+                    old_config,
+                    new_config,
+                }
+            }
+            DEPOSIT_NFT => Self::DepositNft {
+                config: unpack_args(rest)?,
+            },
+            WITHDRAW_NFT => Self::WithdrawNft {
+                config: unpack_args(rest)?,
+            },
+            DEPOSIT_SOL => {
+                let (config, lamports) = unpack_args(rest)?;
+                Self::DepositSol { config, lamports }
+            }
+            WITHDRAW_SOL => {
+                let (config, lamports) = unpack_args(rest)?;
+                Self::WithdrawSol { config, lamports }
+            }
+            BUY_NFT => {
+                let (config, max_price) = unpack_args(rest)?;
+                Self::BuyNft { config, max_price }
+            }
+            SELL_NFT_TOKEN_POOL => {
+                let (config, min_price) = unpack_args(rest)?;
+                Self::SellNftTokenPool { config, min_price }
+            }
+            SELL_NFT_TRADE_POOL => {
+                let (config, min_price) = unpack_args(rest)?;
+                Self::SellNftTradePool { config, min_price }
+            }
+            LIST => Self::List {
+                price: unpack_args(rest)?,
+            },
+            DELIST => Self::Delist,
+            EDIT_SINGLE_LISTING => Self::EditSingleListing {
+                price: unpack_args(rest)?,
+            },
+            BUY_SINGLE_LISTING => Self::BuySingleListing {
+                max_price: unpack_args(rest)?,
+            },
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+/// Extracts the contents of an instruction into small bits and pieces, or what we would call,
+/// instruction_properties.
+///
+/// The function should return a list of instruction properties extracted from an instruction.
+pub async fn fragment_instruction(
+    // The instruction
+    instruction: Instruction,
+) -> Option<InstructionSet> {
+    let unpack_result = TensorswapInstruction::unpack(&instruction.data);
+
+    return match unpack_result {
+        Ok(ref ui) => {
+            let tensorswap_instruction = ui.clone();
+            match tensorswap_instruction {
+                TensorswapInstruction::InitPool { config } => {
+                    let mut properties = vec![];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "init-pool".to_string(),
+                            action: "nft.pool.create".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::ClosePool { config } => {
+                    let mut properties = vec![];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "close-pool".to_string(),
+                            action: "nft.pool.close".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::EditPool { old_config, new_config } => {
+                    let mut properties = vec![];
+                    properties.extend(config_properties(&instruction, &old_config, "old_config"));
+                    properties.extend(config_properties(&instruction, &new_config, "new_config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "edit-pool".to_string(),
+                            action: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::DepositNft { config } => {
+                    let mut properties = vec![];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit-nft".to_string(),
+                            action: "nft.pool.deposit".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::WithdrawNft { config } => {
+                    let mut properties = vec![];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-nft".to_string(),
+                            action: "nft.pool.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::DepositSol { config, lamports } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "lamports".to_string(),
+                            value: lamports.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "deposit-sol".to_string(),
+                            action: "nft.pool.deposit".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::WithdrawSol { config, lamports } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "lamports".to_string(),
+                            value: lamports.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "withdraw-sol".to_string(),
+                            action: "nft.pool.withdraw".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::BuyNft { config, max_price } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "max_price".to_string(),
+                            value: max_price.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "buy-nft".to_string(),
+                            action: "nft.sale".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::SellNftTokenPool { config, min_price } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "min_price".to_string(),
+                            value: min_price.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "sell-nft-token-pool".to_string(),
+                            action: "nft.sale".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::SellNftTradePool { config, min_price } => {
+                    let mut properties = vec![
+                        InstructionProperty {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            key: "min_price".to_string(),
+                            value: min_price.to_string(),
+                            parent_key: "".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                    ];
+                    properties.extend(config_properties(&instruction, &config, "config"));
+
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "sell-nft-trade-pool".to_string(),
+                            action: "nft.sale".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties,
+                    })
+                }
+                TensorswapInstruction::List { price } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "list".to_string(),
+                            action: "nft.list".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "price".to_string(),
+                                value: price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TensorswapInstruction::Delist => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "delist".to_string(),
+                            action: "nft.list.cancel".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![],
+                    })
+                }
+                TensorswapInstruction::EditSingleListing { price } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "edit-single-listing".to_string(),
+                            action: "nft.list".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "price".to_string(),
+                                value: price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+                TensorswapInstruction::BuySingleListing { max_price } => {
+                    Some(InstructionSet {
+                        function: InstructionFunction {
+                            tx_instruction_id: instruction.tx_instruction_id.clone(),
+                            transaction_hash: instruction.transaction_hash.clone(),
+                            parent_index: instruction.parent_index.clone(),
+                            program: instruction.program.clone(),
+                            function_name: "buy-single-listing".to_string(),
+                            action: "nft.sale".to_string(),
+                            timestamp: instruction.timestamp.clone(),
+                        },
+                        properties: vec![
+                            InstructionProperty {
+                                tx_instruction_id: instruction.tx_instruction_id.clone(),
+                                transaction_hash: instruction.transaction_hash.clone(),
+                                parent_index: instruction.parent_index.clone(),
+                                key: "max_price".to_string(),
+                                value: max_price.to_string(),
+                                parent_key: "".to_string(),
+                                timestamp: instruction.timestamp.clone(),
+                            },
+                        ],
+                    })
+                }
+            }
+        }
+        Err(err) => {
+            let err_msg = match err {
+                ProgramError::Custom(_) => "Custom".to_string(),
+                ProgramError::InvalidArgument => "InvalidArgument".to_string(),
+                ProgramError::InvalidInstructionData => "InvalidInstructionData".to_string(),
+                ProgramError::InvalidAccountData => "InvalidAccountData".to_string(),
+                ProgramError::AccountDataTooSmall => "AccountDataTooSmall".to_string(),
+                ProgramError::InsufficientFunds => "InsufficientFunds".to_string(),
+                ProgramError::IncorrectProgramId => "IncorrectProgramId".to_string(),
+                ProgramError::MissingRequiredSignature => "MissingRequiredSignature".to_string(),
+                ProgramError::AccountAlreadyInitialized => "AccountAlreadyInitialized".to_string(),
+                ProgramError::UninitializedAccount => "UninitializedAccount".to_string(),
+                ProgramError::NotEnoughAccountKeys => "NotEnoughAccountKeys".to_string(),
+                ProgramError::AccountBorrowFailed => "AccountBorrowFailed".to_string(),
+                ProgramError::MaxSeedLengthExceeded => "MaxSeedLengthExceeded".to_string(),
+                ProgramError::InvalidSeeds => "InvalidSeeds".to_string(),
+                ProgramError::BorshIoError(_) => "BorshIoError".to_string(),
+                ProgramError::AccountNotRentExempt => "AccountNotRentExempt".to_string(),
+                ProgramError::UnsupportedSysvar => "UnsupportedSysvar".to_string(),
+                ProgramError::IllegalOwner => "IllegalOwner".to_string()
+            };
+
+            error!("{} Reason: {}", "[spi-wrapper/programs/tensorswap] FATAL: Unrecognised instruction.".to_string(),
+                err_msg);
+
+            None
+        }
+    };
+}
+
+/// Flattens a pool configuration into properties under `parent_key`.
+fn config_properties(
+    instruction: &Instruction,
+    config: &PoolConfig,
+    parent_key: &str,
+) -> Vec<InstructionProperty> {
+    vec![
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: parent_key.to_owned() + "/pool_type",
+            value: match config.pool_type {
+                PoolType::Token => "token",
+                PoolType::Nft => "nft",
+                PoolType::Trade => "trade",
+            }
+            .to_string(),
+            parent_key: parent_key.to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: parent_key.to_owned() + "/curve_type",
+            value: match config.curve_type {
+                CurveType::Linear => "linear",
+                CurveType::Exponential => "exponential",
+            }
+            .to_string(),
+            parent_key: parent_key.to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: parent_key.to_owned() + "/starting_price",
+            value: config.starting_price.to_string(),
+            parent_key: parent_key.to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: parent_key.to_owned() + "/delta",
+            value: config.delta.to_string(),
+            parent_key: parent_key.to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: parent_key.to_owned() + "/mm_compound_fees",
+            value: (config.mm_compound_fees as i32).to_string(),
+            parent_key: parent_key.to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+        InstructionProperty {
+            tx_instruction_id: instruction.tx_instruction_id.clone(),
+            transaction_hash: instruction.transaction_hash.clone(),
+            parent_index: instruction.parent_index.clone(),
+            key: parent_key.to_owned() + "/mm_fee_bps",
+            value: if let Some(v) = config.mm_fee_bps {
+                v.to_string()
+            } else {
+                "".to_string()
+            },
+            parent_key: parent_key.to_string(),
+            timestamp: instruction.timestamp.clone(),
+        },
+    ]
+}
@@ -0,0 +1,499 @@
+//! Confidential transfer extension instruction types
+//!
+//! Only the fields that are not encrypted are kept; ciphertexts, decryptable balances and
+//! decrypt handles are skipped over when unpacking.
+//!
+//! The layout is the one of spl-token-2022 v0.9.0, where the proofs are referenced by a single
+//! `proof_instruction_offset`. Later versions reference each proof separately and are not
+//! understood here.
+
+use crate::programs::token_2022::instruction::TokenInstruction;
+use solana_program::{
+    program_error::ProgramError,
+    program_option::COption,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+/// Length of an ElGamal public key
+pub const ELGAMAL_PUBKEY_BYTES: usize = 32;
+/// Length of an authenticated encryption ciphertext, used for decryptable balances
+const AE_CIPHERTEXT_BYTES: usize = 36;
+/// Length of the low and high bits ElGamal decrypt handles of a split transfer
+const SOURCE_DECRYPT_HANDLES_BYTES: usize = 64;
+
+/// Confidential Transfer extension instructions
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfidentialTransferInstruction {
+    // 0
+    /// Initializes confidential transfers for a mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The SPL Token mint.
+    InitializeMint {
+        /// Authority to modify the `ConfidentialTransferMint` configuration and to approve new
+        /// accounts.
+        authority: COption<Pubkey>,
+        /// Determines if newly configured accounts must be approved by the `authority` before they
+        /// may be used by the user.
+        auto_approve_new_accounts: bool,
+        /// New authority to decode any transfer amount in a confidential transfer.
+        auditor_elgamal_pubkey: Option<[u8; ELGAMAL_PUBKEY_BYTES]>,
+    },
+
+    // 1
+    /// Updates the confidential transfer mint configuration for a mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The SPL Token mint.
+    ///   1. `[signer]` Confidential transfer mint authority.
+    UpdateMint {
+        /// Determines if newly configured accounts must be approved by the `authority` before they
+        /// may be used by the user.
+        auto_approve_new_accounts: bool,
+        /// New authority to decode any transfer amount in a confidential transfer.
+        auditor_elgamal_pubkey: Option<[u8; ELGAMAL_PUBKEY_BYTES]>,
+    },
+
+    // 2
+    /// Configures confidential transfers for a token account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writeable]` The SPL Token account.
+    ///   1. `[]` The corresponding SPL Token mint.
+    ///   2. `[]` Instructions sysvar if `VerifyPubkeyValidityProof` is included in the same
+    ///      transaction or context state account if `VerifyPubkeyValidityProof` is pre-verified
+    ///      into a context state account.
+    ///   3. `[signer]` The single source account owner.
+    ConfigureAccount {
+        /// The maximum number of despots and transfers that an account can receiver before the
+        /// `ApplyPendingBalance` is executed
+        maximum_pending_balance_credit_counter: u64,
+        /// Relative location of the proof instruction to this instruction in the transaction. If
+        /// the offset is `0`, then a context state account is used for the proof.
+        proof_instruction_offset: i8,
+    },
+
+    // 3
+    /// Approves a token account for confidential transfers.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The SPL Token account to approve.
+    ///   1. `[]` The SPL Token mint.
+    ///   2. `[signer]` Confidential transfer mint authority.
+    ApproveAccount,
+
+    // 4
+    /// Empties the pending and available balances of a token account, in preparation for closing
+    /// it.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[]` Instructions sysvar or context state account.
+    ///   2. `[signer]` The single account owner.
+    EmptyAccount {
+        /// Relative location of the proof instruction to this instruction in the transaction. If
+        /// the offset is `0`, then a context state account is used for the proof.
+        proof_instruction_offset: i8,
+    },
+
+    // 5
+    /// Deposits SPL Tokens into the pending balance of a confidential token account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The single account owner or delegate.
+    Deposit {
+        /// The amount of tokens to deposit
+        amount: u64,
+        /// Expected number of base 10 digits to the right of the decimal place
+        decimals: u8,
+    },
+
+    // 6
+    /// Withdraws SPL Tokens from the available balance of a confidential token account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[]` Instructions sysvar or context state account.
+    ///   3. `[signer]` The single source account owner.
+    Withdraw {
+        /// The amount of tokens to withdraw
+        amount: u64,
+        /// Expected number of base 10 digits to the right of the decimal place
+        decimals: u8,
+        /// Relative location of the proof instruction to this instruction in the transaction. If
+        /// the offset is `0`, then a context state account is used for the proof.
+        proof_instruction_offset: i8,
+    },
+
+    // 7
+    /// Transfers tokens confidentially from one account to another.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source SPL Token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination SPL Token account.
+    ///   3. `[]` Instructions sysvar or context state account.
+    ///   4. `[signer]` The single source account owner.
+    Transfer {
+        /// Relative location of the proof instruction to this instruction in the transaction. If
+        /// the offset is `0`, then a context state account is used for the proof.
+        proof_instruction_offset: i8,
+    },
+
+    // 8
+    /// Applies the pending balance to the available balance, based on the history of `Deposit`
+    /// and/or `Transfer` instructions.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[signer]` The single account owner.
+    ApplyPendingBalance {
+        /// The expected number of pending balance credits since the last successful
+        /// `ApplyPendingBalance` instruction
+        expected_pending_balance_credit_counter: u64,
+    },
+
+    // 9
+    /// Configures an account to accept incoming confidential transfers.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[signer]` Single authority.
+    EnableConfidentialCredits,
+
+    // 10
+    /// Configures an account to reject incoming confidential transfers.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[signer]` The single account owner.
+    DisableConfidentialCredits,
+
+    // 11
+    /// Configures an account to accept incoming non-confidential transfers.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[signer]` The single account owner.
+    EnableNonConfidentialCredits,
+
+    // 12
+    /// Configures an account to reject incoming non-confidential transfers.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The SPL Token account.
+    ///   1. `[signer]` The single account owner.
+    DisableNonConfidentialCredits,
+
+    // 13
+    /// Transfers tokens confidentially from one account to another, with the proofs split across
+    /// pre-verified context state accounts.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The source SPL Token account.
+    ///   1. `[]` The token mint.
+    ///   2. `[writable]` The destination SPL Token account.
+    ///   3. `[]` Context state account for the equality proof.
+    ///   4. `[]` Context state account for the ciphertext validity proof.
+    ///   5. `[]` Context state account for the range proof.
+    ///   6. `[signer]` The source account owner.
+    TransferWithSplitProofs {
+        /// If true, execute no op when an associated context state account is not initialized.
+        no_op_on_uninitialized_split_context_state: bool,
+        /// Close associated context states after a complete execution of the transfer instruction.
+        close_split_context_state_on_execution: bool,
+    },
+}
+
+impl ConfidentialTransferInstruction {
+    /// Unpacks a byte buffer into a ConfidentialTransferInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match tag {
+            0 => {
+                let (authority, rest) = Self::unpack_optional_nonzero_pubkey(rest)?;
+                let (auto_approve_new_accounts, rest) = Self::unpack_bool(rest)?;
+                let (auditor_elgamal_pubkey, _rest) = Self::unpack_optional_elgamal_pubkey(rest)?;
+                Self::InitializeMint {
+                    authority,
+                    auto_approve_new_accounts,
+                    auditor_elgamal_pubkey,
+                }
+            }
+            1 => {
+                let (auto_approve_new_accounts, rest) = Self::unpack_bool(rest)?;
+                let (auditor_elgamal_pubkey, _rest) = Self::unpack_optional_elgamal_pubkey(rest)?;
+                Self::UpdateMint {
+                    auto_approve_new_accounts,
+                    auditor_elgamal_pubkey,
+                }
+            }
+            2 => {
+                let rest = Self::skip(rest, AE_CIPHERTEXT_BYTES)?;
+                let (maximum_pending_balance_credit_counter, rest) =
+                    TokenInstruction::unpack_u64(rest)?;
+                let (proof_instruction_offset, _rest) = Self::unpack_i8(rest)?;
+                Self::ConfigureAccount {
+                    maximum_pending_balance_credit_counter,
+                    proof_instruction_offset,
+                }
+            }
+            3 => Self::ApproveAccount,
+            4 => {
+                let (proof_instruction_offset, _rest) = Self::unpack_i8(rest)?;
+                Self::EmptyAccount {
+                    proof_instruction_offset,
+                }
+            }
+            5 => {
+                let (amount, decimals, _rest) = TokenInstruction::unpack_amount_decimals(rest)?;
+                Self::Deposit { amount, decimals }
+            }
+            6 => {
+                let (amount, decimals, rest) = TokenInstruction::unpack_amount_decimals(rest)?;
+                let rest = Self::skip(rest, AE_CIPHERTEXT_BYTES)?;
+                let (proof_instruction_offset, _rest) = Self::unpack_i8(rest)?;
+                Self::Withdraw {
+                    amount,
+                    decimals,
+                    proof_instruction_offset,
+                }
+            }
+            7 => {
+                let rest = Self::skip(rest, AE_CIPHERTEXT_BYTES)?;
+                let (proof_instruction_offset, _rest) = Self::unpack_i8(rest)?;
+                Self::Transfer {
+                    proof_instruction_offset,
+                }
+            }
+            8 => {
+                let (expected_pending_balance_credit_counter, rest) =
+                    TokenInstruction::unpack_u64(rest)?;
+                Self::skip(rest, AE_CIPHERTEXT_BYTES)?;
+                Self::ApplyPendingBalance {
+                    expected_pending_balance_credit_counter,
+                }
+            }
+            9 => Self::EnableConfidentialCredits,
+            10 => Self::DisableConfidentialCredits,
+            11 => Self::EnableNonConfidentialCredits,
+            12 => Self::DisableNonConfidentialCredits,
+            13 => {
+                let rest = Self::skip(rest, AE_CIPHERTEXT_BYTES)?;
+                let (no_op_on_uninitialized_split_context_state, rest) = Self::unpack_bool(rest)?;
+                let (close_split_context_state_on_execution, rest) = Self::unpack_bool(rest)?;
+                Self::skip(rest, SOURCE_DECRYPT_HANDLES_BYTES)?;
+                Self::TransferWithSplitProofs {
+                    no_op_on_uninitialized_split_context_state,
+                    close_split_context_state_on_execution,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    fn skip(input: &[u8], len: usize) -> Result<&[u8], ProgramError> {
+        input.get(len..).ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn unpack_bool(input: &[u8]) -> Result<(bool, &[u8]), ProgramError> {
+        let (&value, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok((value != 0, rest))
+    }
+
+    fn unpack_i8(input: &[u8]) -> Result<(i8, &[u8]), ProgramError> {
+        let (&value, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok((value as i8, rest))
+    }
+
+    /// A zeroed out pubkey stands for none
+    fn unpack_optional_nonzero_pubkey(
+        input: &[u8],
+    ) -> Result<(COption<Pubkey>, &[u8]), ProgramError> {
+        let (pk, rest) = TokenInstruction::unpack_pubkey(input)?;
+        if pk.to_bytes() == [0u8; PUBKEY_BYTES] {
+            Ok((COption::None, rest))
+        } else {
+            Ok((COption::Some(pk), rest))
+        }
+    }
+
+    /// A zeroed out ElGamal pubkey stands for none
+    fn unpack_optional_elgamal_pubkey(
+        input: &[u8],
+    ) -> Result<(Option<[u8; ELGAMAL_PUBKEY_BYTES]>, &[u8]), ProgramError> {
+        let mut pk = [0u8; ELGAMAL_PUBKEY_BYTES];
+        pk.copy_from_slice(
+            input
+                .get(..ELGAMAL_PUBKEY_BYTES)
+                .ok_or(ProgramError::InvalidInstructionData)?,
+        );
+        let rest = &input[ELGAMAL_PUBKEY_BYTES..];
+        if pk == [0u8; ELGAMAL_PUBKEY_BYTES] {
+            Ok((None, rest))
+        } else {
+            Ok((Some(pk), rest))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unpack_initialize_mint() {
+        let authority = Pubkey::new(&[1u8; 32]);
+        let mut data = vec![27, 0];
+        data.extend_from_slice(authority.as_ref());
+        data.push(1);
+        data.extend_from_slice(&[2u8; ELGAMAL_PUBKEY_BYTES]);
+        assert_eq!(
+            TokenInstruction::unpack(&data).unwrap(),
+            TokenInstruction::ConfidentialTransferExtension(
+                ConfidentialTransferInstruction::InitializeMint {
+                    authority: COption::Some(authority),
+                    auto_approve_new_accounts: true,
+                    auditor_elgamal_pubkey: Some([2u8; ELGAMAL_PUBKEY_BYTES]),
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_unpack_update_mint_without_auditor() {
+        let mut data = vec![1, 0];
+        data.extend_from_slice(&[0u8; ELGAMAL_PUBKEY_BYTES]);
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&data).unwrap(),
+            ConfidentialTransferInstruction::UpdateMint {
+                auto_approve_new_accounts: false,
+                auditor_elgamal_pubkey: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_configure_account() {
+        let mut data = vec![2];
+        data.extend_from_slice(&[7u8; AE_CIPHERTEXT_BYTES]);
+        data.extend_from_slice(&65_536u64.to_le_bytes());
+        data.push(1);
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&data).unwrap(),
+            ConfidentialTransferInstruction::ConfigureAccount {
+                maximum_pending_balance_credit_counter: 65_536,
+                proof_instruction_offset: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_deposit_and_withdraw() {
+        let mut data = vec![5];
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.push(2);
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&data).unwrap(),
+            ConfidentialTransferInstruction::Deposit {
+                amount: 100,
+                decimals: 2,
+            }
+        );
+
+        let mut data = vec![6];
+        data.extend_from_slice(&100u64.to_le_bytes());
+        data.push(2);
+        data.extend_from_slice(&[7u8; AE_CIPHERTEXT_BYTES]);
+        data.push(-1i8 as u8);
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&data).unwrap(),
+            ConfidentialTransferInstruction::Withdraw {
+                amount: 100,
+                decimals: 2,
+                proof_instruction_offset: -1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_transfers() {
+        let mut data = vec![7];
+        data.extend_from_slice(&[7u8; AE_CIPHERTEXT_BYTES]);
+        data.push(1);
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&data).unwrap(),
+            ConfidentialTransferInstruction::Transfer {
+                proof_instruction_offset: 1,
+            }
+        );
+
+        let mut data = vec![13];
+        data.extend_from_slice(&[7u8; AE_CIPHERTEXT_BYTES]);
+        data.extend_from_slice(&[1, 0]);
+        data.extend_from_slice(&[8u8; SOURCE_DECRYPT_HANDLES_BYTES]);
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&data).unwrap(),
+            ConfidentialTransferInstruction::TransferWithSplitProofs {
+                no_op_on_uninitialized_split_context_state: true,
+                close_split_context_state_on_execution: false,
+            }
+        );
+        assert!(ConfidentialTransferInstruction::unpack(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_pending_balance_and_credits() {
+        let mut data = vec![8];
+        data.extend_from_slice(&3u64.to_le_bytes());
+        data.extend_from_slice(&[7u8; AE_CIPHERTEXT_BYTES]);
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&data).unwrap(),
+            ConfidentialTransferInstruction::ApplyPendingBalance {
+                expected_pending_balance_credit_counter: 3,
+            }
+        );
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&[9]).unwrap(),
+            ConfidentialTransferInstruction::EnableConfidentialCredits
+        );
+        assert_eq!(
+            ConfidentialTransferInstruction::unpack(&[12]).unwrap(),
+            ConfidentialTransferInstruction::DisableNonConfidentialCredits
+        );
+        assert!(ConfidentialTransferInstruction::unpack(&[14]).is_err());
+    }
+}
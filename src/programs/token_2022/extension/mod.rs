@@ -0,0 +1,4 @@
+//! Extension instruction types
+
+pub mod confidential_transfer;
+pub mod transfer_fee;
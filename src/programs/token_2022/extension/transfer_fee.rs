@@ -0,0 +1,222 @@
+//! Transfer fee extension instruction types
+
+use crate::programs::token_2022::instruction::TokenInstruction;
+use solana_program::{program_error::ProgramError, program_option::COption, pubkey::Pubkey};
+
+/// Transfer Fee extension instructions
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferFeeInstruction {
+    // 0
+    /// Initialize the transfer fee on a new mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    InitializeTransferFeeConfig {
+        /// Pubkey that may update the fees
+        transfer_fee_config_authority: COption<Pubkey>,
+        /// Withdraw instructions must be signed by this key
+        withdraw_withheld_authority: COption<Pubkey>,
+        /// Amount of transfer collected as fees, expressed as basis points of the transfer amount
+        transfer_fee_basis_points: u16,
+        /// Maximum fee assessed on transfers
+        maximum_fee: u64,
+    },
+
+    // 1
+    /// Transfer, providing expected mint information and fees
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account. Must include the `TransferFeeAmount` extension.
+    ///   1. `[]` The token mint. Must include the `TransferFeeConfig` extension.
+    ///   2. `[writable]` The destination account. Must include the `TransferFeeAmount` extension.
+    ///   3. `[signer]` The source account's owner/delegate.
+    TransferCheckedWithFee {
+        /// The amount of tokens to transfer.
+        amount: u64,
+        /// Expected number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+        /// Expected fee assessed on this transfer, calculated off-chain based on the
+        /// transfer_fee_basis_points and maximum_fee of the mint.
+        fee: u64,
+    },
+
+    // 2
+    /// Transfer all withheld tokens in the mint to an account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The token mint. Must include the `TransferFeeConfig` extension.
+    ///   1. `[writable]` The fee receiver account.
+    ///   2. `[signer]` The mint's `withdraw_withheld_authority`.
+    WithdrawWithheldTokensFromMint,
+
+    // 3
+    /// Transfer all withheld tokens to an account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[]` The token mint. Must include the `TransferFeeConfig` extension.
+    ///   1. `[writable]` The fee receiver account.
+    ///   2. `[signer]` The mint's `withdraw_withheld_authority`.
+    ///   3. ..3+N `[writable]` The source accounts to withdraw from.
+    WithdrawWithheldTokensFromAccounts {
+        /// Number of token accounts harvested
+        num_token_accounts: u8,
+    },
+
+    // 4
+    /// Permissionless instruction to transfer all withheld tokens to the mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint.
+    ///   1. ..1+N `[writable]` The source accounts to harvest from.
+    HarvestWithheldTokensToMint,
+
+    // 5
+    /// Set transfer fee. Only supported for mints that include the `TransferFeeConfig` extension.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint.
+    ///   1. `[signer]` The mint's fee account owner.
+    SetTransferFee {
+        /// Amount of transfer collected as fees, expressed as basis points of the transfer amount
+        transfer_fee_basis_points: u16,
+        /// Maximum fee assessed on transfers
+        maximum_fee: u64,
+    },
+}
+
+impl TransferFeeInstruction {
+    /// Unpacks a byte buffer into a TransferFeeInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match tag {
+            0 => {
+                let (transfer_fee_config_authority, rest) =
+                    TokenInstruction::unpack_pubkey_option(rest)?;
+                let (withdraw_withheld_authority, rest) =
+                    TokenInstruction::unpack_pubkey_option(rest)?;
+                let (transfer_fee_basis_points, rest) = TokenInstruction::unpack_u16(rest)?;
+                let (maximum_fee, _rest) = TokenInstruction::unpack_u64(rest)?;
+                Self::InitializeTransferFeeConfig {
+                    transfer_fee_config_authority,
+                    withdraw_withheld_authority,
+                    transfer_fee_basis_points,
+                    maximum_fee,
+                }
+            }
+            1 => {
+                let (amount, decimals, rest) = TokenInstruction::unpack_amount_decimals(rest)?;
+                let (fee, _rest) = TokenInstruction::unpack_u64(rest)?;
+                Self::TransferCheckedWithFee {
+                    amount,
+                    decimals,
+                    fee,
+                }
+            }
+            2 => Self::WithdrawWithheldTokensFromMint,
+            3 => {
+                let &num_token_accounts = rest
+                    .first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                Self::WithdrawWithheldTokensFromAccounts { num_token_accounts }
+            }
+            4 => Self::HarvestWithheldTokensToMint,
+            5 => {
+                let (transfer_fee_basis_points, rest) = TokenInstruction::unpack_u16(rest)?;
+                let (maximum_fee, _rest) = TokenInstruction::unpack_u64(rest)?;
+                Self::SetTransferFee {
+                    transfer_fee_basis_points,
+                    maximum_fee,
+                }
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unpack_initialize_transfer_fee_config() {
+        let authority = Pubkey::new(&[1u8; 32]);
+        let mut data = vec![26, 0, 1];
+        data.extend_from_slice(authority.as_ref());
+        data.push(0);
+        data.extend_from_slice(&50u16.to_le_bytes());
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        assert_eq!(
+            TokenInstruction::unpack(&data).unwrap(),
+            TokenInstruction::TransferFeeExtension(
+                TransferFeeInstruction::InitializeTransferFeeConfig {
+                    transfer_fee_config_authority: COption::Some(authority),
+                    withdraw_withheld_authority: COption::None,
+                    transfer_fee_basis_points: 50,
+                    maximum_fee: 1_000,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_unpack_transfer_checked_with_fee() {
+        let mut data = vec![1];
+        data.extend_from_slice(&10_000u64.to_le_bytes());
+        data.push(9);
+        data.extend_from_slice(&5u64.to_le_bytes());
+        assert_eq!(
+            TransferFeeInstruction::unpack(&data).unwrap(),
+            TransferFeeInstruction::TransferCheckedWithFee {
+                amount: 10_000,
+                decimals: 9,
+                fee: 5,
+            }
+        );
+        assert!(TransferFeeInstruction::unpack(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_withheld_tokens() {
+        assert_eq!(
+            TransferFeeInstruction::unpack(&[2]).unwrap(),
+            TransferFeeInstruction::WithdrawWithheldTokensFromMint
+        );
+        assert_eq!(
+            TransferFeeInstruction::unpack(&[3, 4]).unwrap(),
+            TransferFeeInstruction::WithdrawWithheldTokensFromAccounts {
+                num_token_accounts: 4,
+            }
+        );
+        assert_eq!(
+            TransferFeeInstruction::unpack(&[4]).unwrap(),
+            TransferFeeInstruction::HarvestWithheldTokensToMint
+        );
+    }
+
+    #[test]
+    fn test_unpack_set_transfer_fee() {
+        let mut data = vec![5];
+        data.extend_from_slice(&25u16.to_le_bytes());
+        data.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(
+            TransferFeeInstruction::unpack(&data).unwrap(),
+            TransferFeeInstruction::SetTransferFee {
+                transfer_fee_basis_points: 25,
+                maximum_fee: u64::MAX,
+            }
+        );
+        assert!(TransferFeeInstruction::unpack(&[6]).is_err());
+    }
+}
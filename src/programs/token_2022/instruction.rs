@@ -0,0 +1,474 @@
+//! Instruction types
+
+use crate::programs::token_2022::extension::{
+    confidential_transfer::ConfidentialTransferInstruction, transfer_fee::TransferFeeInstruction,
+};
+use solana_program::{
+    program_error::ProgramError,
+    program_option::COption,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+use std::{convert::TryInto, mem::size_of};
+
+const U16_BYTES: usize = 2;
+const U64_BYTES: usize = 8;
+
+/// Instructions supported by the token-2022 program which are either new, or whose layout differs
+/// from the SPL Token program. Every other instruction is shared with SPL Token as is.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TokenInstruction {
+    // 6
+    /// Sets a new authority of a mint or account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single authority
+    ///   0. `[writable]` The mint or account to change the authority of.
+    ///   1. `[signer]` The current authority of the mint or account.
+    ///
+    ///   * Multisignature authority
+    ///   0. `[writable]` The mint or account to change the authority of.
+    ///   1. `[]` The mint's or account's current multisignature authority.
+    ///   2. ..2+M `[signer]` M signer accounts
+    SetAuthority {
+        /// The type of authority to update, token-2022 extends these with extension authorities.
+        authority_type: u8,
+        /// The new authority
+        new_authority: COption<Pubkey>,
+    },
+
+    // 18
+    /// Like InitializeAccount2, but does not require the Rent sysvar to be provided
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]`  The account to initialize.
+    ///   1. `[]` The mint this account will be associated with.
+    InitializeAccount3 {
+        /// The new account's owner/multisignature.
+        owner: Pubkey,
+    },
+
+    // 19
+    /// Like InitializeMultisig, but does not require the Rent sysvar to be provided
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The multisignature account to initialize.
+    ///   1. ..1+N. `[]` The signer accounts, must equal to N where 1 <= N <= 11.
+    InitializeMultisig2 {
+        /// The number of signers (M) required to validate this multisignature account.
+        m: u8,
+    },
+
+    // 20
+    /// Like InitializeMint, but does not require the Rent sysvar to be provided
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    InitializeMint2 {
+        /// Number of base 10 digits to the right of the decimal place.
+        decimals: u8,
+        /// The authority/multisignature to mint tokens.
+        mint_authority: Pubkey,
+        /// The freeze authority/multisignature of the mint.
+        freeze_authority: COption<Pubkey>,
+    },
+
+    // 21
+    /// Gets the required size of an account for the given mint as a little-endian `u64`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The mint to calculate for
+    GetAccountDataSize {
+        /// Additional extension types to include in the returned account size
+        extension_types: Vec<u16>,
+    },
+
+    // 22
+    /// Initialize the Immutable Owner extension for the given token account
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]`  The account to initialize.
+    InitializeImmutableOwner,
+
+    // 23
+    /// Convert an Amount of tokens to a UiAmount `string`, using the given mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The mint to calculate for
+    AmountToUiAmount {
+        /// The amount of tokens to convert.
+        amount: u64,
+    },
+
+    // 24
+    /// Convert a UiAmount of tokens to a little-endian `u64` raw Amount, using the given mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[]` The mint to calculate for
+    UiAmountToAmount {
+        /// The ui_amount of tokens to convert.
+        ui_amount: String,
+    },
+
+    // 25
+    /// Initialize the close account authority on a new mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    InitializeMintCloseAuthority {
+        /// Authority that must sign the `CloseAccount` instruction on a mint
+        close_authority: COption<Pubkey>,
+    },
+
+    // 26
+    /// The common instruction prefix for Transfer Fee extension instructions.
+    TransferFeeExtension(TransferFeeInstruction),
+
+    // 27
+    /// The common instruction prefix for Confidential Transfer extension instructions.
+    ConfidentialTransferExtension(ConfidentialTransferInstruction),
+
+    // 28
+    /// The common instruction prefix for Default Account State extension instructions.
+    DefaultAccountStateExtension(u8),
+
+    // 29
+    /// Check to see if a token account is large enough for a list of ExtensionTypes, and if not,
+    /// use reallocation to increase the data size.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The account to reallocate.
+    ///   1. `[signer, writable]` The payer account to fund reallocation
+    ///   2. `[]` System program for reallocation funding
+    ///   3. `[signer]` The account's owner.
+    Reallocate {
+        /// New extension types to include in the reallocated account
+        extension_types: Vec<u16>,
+    },
+
+    // 30
+    /// The common instruction prefix for Memo Transfer account extension instructions.
+    MemoTransferExtension(u8),
+
+    // 31
+    /// Creates the native mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writeable,signer]` Funding account (must be a system account)
+    ///   1. `[writable]` The native mint address
+    ///   2. `[]` System program for mint account funding
+    CreateNativeMint,
+
+    // 32
+    /// Initialize the non transferable extension for the given mint account
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]`  The mint account to initialize.
+    InitializeNonTransferableMint,
+
+    // 33
+    /// The common instruction prefix for Interest Bearing extension instructions.
+    InterestBearingMintExtension(u8),
+
+    // 34
+    /// The common instruction prefix for CPI Guard account extension instructions.
+    CpiGuardExtension(u8),
+
+    // 35
+    /// Initialize the permanent delegate on a new mint.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    InitializePermanentDelegate {
+        /// Authority that may sign for `Transfer`s and `Burn`s on any account
+        delegate: Pubkey,
+    },
+
+    // 36
+    /// The common instruction prefix for transfer hook extension instructions.
+    TransferHookExtension(u8),
+
+    // 37
+    /// The common instruction prefix for the confidential transfer fee extension instructions.
+    ConfidentialTransferFeeExtension(u8),
+
+    // 38
+    /// Rescues SOL sent to any TokenProgram owned account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Source Account owned by the token program
+    ///   1. `[writable]` Destination account
+    ///   2. `[signer]` Authority
+    ///   3. ..2+M `[signer]` M signer accounts.
+    WithdrawExcessLamports,
+
+    // 39
+    /// The common instruction prefix for metadata pointer extension instructions.
+    MetadataPointerExtension(u8),
+}
+
+impl TokenInstruction {
+    /// Returns true if the instruction tag is shared, layout included, with the SPL Token program.
+    pub fn is_shared_with_spl_token(tag: u8) -> bool {
+        tag <= 17 && tag != 6
+    }
+
+    /// Unpacks a byte buffer into a [TokenInstruction](enum.TokenInstruction.html).
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match tag {
+            6 => {
+                let (&authority_type, rest) = rest
+                    .split_first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let (new_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::SetAuthority {
+                    authority_type,
+                    new_authority,
+                }
+            }
+            18 => {
+                let (owner, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitializeAccount3 { owner }
+            }
+            19 => {
+                let &m = rest.first().ok_or(ProgramError::InvalidInstructionData)?;
+                Self::InitializeMultisig2 { m }
+            }
+            20 => {
+                let (&decimals, rest) = rest
+                    .split_first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let (mint_authority, rest) = Self::unpack_pubkey(rest)?;
+                let (freeze_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::InitializeMint2 {
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
+                }
+            }
+            21 => {
+                let extension_types = Self::unpack_extension_types(rest)?;
+                Self::GetAccountDataSize { extension_types }
+            }
+            22 => Self::InitializeImmutableOwner,
+            23 => {
+                let (amount, _rest) = Self::unpack_u64(rest)?;
+                Self::AmountToUiAmount { amount }
+            }
+            24 => {
+                let ui_amount = std::str::from_utf8(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?
+                    .to_string();
+                Self::UiAmountToAmount { ui_amount }
+            }
+            25 => {
+                let (close_authority, _rest) = Self::unpack_pubkey_option(rest)?;
+                Self::InitializeMintCloseAuthority { close_authority }
+            }
+            26 => Self::TransferFeeExtension(TransferFeeInstruction::unpack(rest)?),
+            27 => {
+                Self::ConfidentialTransferExtension(ConfidentialTransferInstruction::unpack(rest)?)
+            }
+            28 => Self::DefaultAccountStateExtension(Self::unpack_extension_tag(rest)?),
+            29 => {
+                let extension_types = Self::unpack_extension_types(rest)?;
+                Self::Reallocate { extension_types }
+            }
+            30 => Self::MemoTransferExtension(Self::unpack_extension_tag(rest)?),
+            31 => Self::CreateNativeMint,
+            32 => Self::InitializeNonTransferableMint,
+            33 => Self::InterestBearingMintExtension(Self::unpack_extension_tag(rest)?),
+            34 => Self::CpiGuardExtension(Self::unpack_extension_tag(rest)?),
+            35 => {
+                let (delegate, _rest) = Self::unpack_pubkey(rest)?;
+                Self::InitializePermanentDelegate { delegate }
+            }
+            36 => Self::TransferHookExtension(Self::unpack_extension_tag(rest)?),
+            37 => Self::ConfidentialTransferFeeExtension(Self::unpack_extension_tag(rest)?),
+            38 => Self::WithdrawExcessLamports,
+            39 => Self::MetadataPointerExtension(Self::unpack_extension_tag(rest)?),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    pub(crate) fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
+        if input.len() < PUBKEY_BYTES {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let (key, rest) = input.split_at(PUBKEY_BYTES);
+        Ok((Pubkey::new(key), rest))
+    }
+
+    pub(crate) fn unpack_pubkey_option(
+        input: &[u8],
+    ) -> Result<(COption<Pubkey>, &[u8]), ProgramError> {
+        match input.split_first() {
+            Option::Some((&0, rest)) => Ok((COption::None, rest)),
+            Option::Some((&1, rest)) => {
+                let (pk, rest) = Self::unpack_pubkey(rest)?;
+                Ok((COption::Some(pk), rest))
+            }
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    pub(crate) fn unpack_u64(input: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
+        let value = input
+            .get(..U64_BYTES)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok((value, &input[U64_BYTES..]))
+    }
+
+    pub(crate) fn unpack_u16(input: &[u8]) -> Result<(u16, &[u8]), ProgramError> {
+        let value = input
+            .get(..U16_BYTES)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u16::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok((value, &input[U16_BYTES..]))
+    }
+
+    pub(crate) fn unpack_amount_decimals(input: &[u8]) -> Result<(u64, u8, &[u8]), ProgramError> {
+        let (amount, rest) = Self::unpack_u64(input)?;
+        let (&decimals, rest) = rest
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok((amount, decimals, rest))
+    }
+
+    fn unpack_extension_tag(input: &[u8]) -> Result<u8, ProgramError> {
+        input.first().copied().ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn unpack_extension_types(input: &[u8]) -> Result<Vec<u16>, ProgramError> {
+        if input.len() % size_of::<u16>() != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(input
+            .chunks(size_of::<u16>())
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unpack_set_authority() {
+        let new_authority = Pubkey::new(&[1u8; 32]);
+        let mut data = vec![6, 4, 1];
+        data.extend_from_slice(new_authority.as_ref());
+        assert_eq!(
+            TokenInstruction::unpack(&data).unwrap(),
+            TokenInstruction::SetAuthority {
+                authority_type: 4,
+                new_authority: COption::Some(new_authority),
+            }
+        );
+
+        assert_eq!(
+            TokenInstruction::unpack(&[6, 4, 0]).unwrap(),
+            TokenInstruction::SetAuthority {
+                authority_type: 4,
+                new_authority: COption::None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_initialize_mint_2() {
+        let mint_authority = Pubkey::new(&[2u8; 32]);
+        let freeze_authority = Pubkey::new(&[3u8; 32]);
+        let mut data = vec![20, 6];
+        data.extend_from_slice(mint_authority.as_ref());
+        data.push(1);
+        data.extend_from_slice(freeze_authority.as_ref());
+        assert_eq!(
+            TokenInstruction::unpack(&data).unwrap(),
+            TokenInstruction::InitializeMint2 {
+                decimals: 6,
+                mint_authority,
+                freeze_authority: COption::Some(freeze_authority),
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_extension_types() {
+        assert_eq!(
+            TokenInstruction::unpack(&[21, 1, 0, 7, 0]).unwrap(),
+            TokenInstruction::GetAccountDataSize {
+                extension_types: vec![1, 7],
+            }
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[29, 8, 0]).unwrap(),
+            TokenInstruction::Reallocate {
+                extension_types: vec![8],
+            }
+        );
+        assert!(TokenInstruction::unpack(&[29, 8]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_amounts() {
+        let mut data = vec![23];
+        data.extend_from_slice(&42u64.to_le_bytes());
+        assert_eq!(
+            TokenInstruction::unpack(&data).unwrap(),
+            TokenInstruction::AmountToUiAmount { amount: 42 }
+        );
+
+        let mut data = vec![24];
+        data.extend_from_slice(b"0.42");
+        assert_eq!(
+            TokenInstruction::unpack(&data).unwrap(),
+            TokenInstruction::UiAmountToAmount {
+                ui_amount: "0.42".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_extension_prefixes() {
+        assert_eq!(
+            TokenInstruction::unpack(&[28, 1]).unwrap(),
+            TokenInstruction::DefaultAccountStateExtension(1)
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[30, 0]).unwrap(),
+            TokenInstruction::MemoTransferExtension(0)
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[39, 1]).unwrap(),
+            TokenInstruction::MetadataPointerExtension(1)
+        );
+        assert!(TokenInstruction::unpack(&[34]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_unknown_tag() {
+        assert!(TokenInstruction::unpack(&[]).is_err());
+        assert!(TokenInstruction::unpack(&[40]).is_err());
+    }
+}
@@ -0,0 +1,6 @@
+//! Vendored token-2022 definitions, trimmed to what is needed to interpret its instructions.
+//!
+//! The layouts mirror spl-token-2022 v0.9.0, up to the metadata pointer extension (tag 39).
+
+pub mod extension;
+pub mod instruction;
@@ -0,0 +1,73 @@
+//! Instruction types
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::programs::token_auth_rules::payload::Payload;
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+/// Args for `create` instruction.
+pub enum CreateOrUpdateArgs {
+    /// V1 implementation of the `create` instruction arguments.
+    V1 {
+        /// RuleSet pre-serialized by caller into the MessagePack format.
+        serialized_rule_set: Vec<u8>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+/// Args for `validate` instruction.
+pub enum ValidateArgs {
+    /// V1 implementation of the `validate` instruction arguments.
+    V1 {
+        /// `Operation` to validate.
+        operation: String,
+        /// `Payload` data used for rule validation.
+        payload: Payload,
+        /// Update any relevant state stored in Rule, such as the Frequency `last_update` time value.
+        update_rule_state: bool,
+        /// Optional revision of the `RuleSet` to use.  If `None`, the latest `RuleSet` revision is
+        /// used.
+        rule_set_revision: Option<usize>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+/// Args for `write_to_buffer` instruction.
+pub enum WriteToBufferArgs {
+    /// V1 implementation of the `write_to_buffer` instruction arguments.
+    V1 {
+        /// RuleSet pre-serialized by caller into the MessagePack format.
+        serialized_rule_set: Vec<u8>,
+        /// Whether the or not to overwrite the buffer.
+        overwrite: bool,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+/// Args for `puff_rule_set` instruction.
+pub enum PuffRuleSetArgs {
+    /// V1 implementation of the `puff_rule_set` instruction arguments.
+    V1 {
+        /// RuleSet name.
+        rule_set_name: String,
+    },
+}
+
+/// Instructions available in this program.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum RuleSetInstruction {
+    /// This instruction stores a caller-pre-serialized `RuleSet` into the rule_set PDA account.
+    CreateOrUpdate(CreateOrUpdateArgs),
+
+    /// This instruction executes the RuleSet stored in the rule_set PDA account by calling the
+    /// `RuleSet`'s `validate` method.
+    Validate(ValidateArgs),
+
+    /// This instruction appends a pre-serialized `RuleSet` chunk into the rule_set PDA account.
+    /// Needed with large `RuleSet`s to stay within transaction size limit.
+    WriteToBuffer(WriteToBufferArgs),
+
+    /// Add space to the end of a rule set account.  Needed with large `RuleSet`s to pre-allocate
+    /// the space for the rule set, and only if the overall number of rules is large enough.
+    PuffRuleSet(PuffRuleSetArgs),
+}
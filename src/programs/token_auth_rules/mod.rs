@@ -0,0 +1,5 @@
+//! Vendored mpl-token-auth-rules definitions, trimmed to what is needed to interpret its
+//! instructions.
+
+pub mod instruction;
+pub mod payload;
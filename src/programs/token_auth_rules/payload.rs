@@ -0,0 +1,40 @@
+//! Payload types passed to rule set validation
+
+use std::collections::BTreeMap;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// A seed path type used by the `DerivedKeyMatch` rule.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct SeedsVec {
+    /// The vector of derivation seeds.
+    pub seeds: Vec<Vec<u8>>,
+}
+
+/// A proof type used by the `PubkeyTreeMatch` rule.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct ProofInfo {
+    /// The merkle proof.
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Variants representing the different types represented in a payload.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum PayloadType {
+    /// A plain `Pubkey`.
+    Pubkey(Pubkey),
+    /// PDA derivation seeds.
+    Seeds(SeedsVec),
+    /// A merkle proof.
+    MerkleProof(ProofInfo),
+    /// A plain `u64` used for `Amount`.
+    Number(u64),
+}
+
+/// A wrapper type for the payload hashmap. Borsh writes maps as a length followed by the
+/// entries, so an ordered map reads the same bytes and keeps the output stable.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Payload {
+    pub map: BTreeMap<String, PayloadType>,
+}
@@ -0,0 +1,383 @@
+//! Instruction types
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::programs::anchor::unpack_args;
+use crate::programs::token_metadata::state::{AssetData, CollectionDetails, Data, DataV2, PrintSupply};
+
+// Instruction tags this decoder interprets. Every other tag up to `LAST_TAG` is still a valid
+// instruction and is reported by name only.
+pub const CREATE_METADATA_ACCOUNT: u8 = 0;
+pub const UPDATE_METADATA_ACCOUNT: u8 = 1;
+pub const CREATE_MASTER_EDITION: u8 = 10;
+pub const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN: u8 = 11;
+pub const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+pub const CREATE_METADATA_ACCOUNT_V2: u8 = 16;
+pub const CREATE_MASTER_EDITION_V3: u8 = 17;
+pub const VERIFY_COLLECTION: u8 = 18;
+pub const UNVERIFY_COLLECTION: u8 = 22;
+pub const SET_AND_VERIFY_COLLECTION: u8 = 25;
+pub const VERIFY_SIZED_COLLECTION_ITEM: u8 = 30;
+pub const UNVERIFY_SIZED_COLLECTION_ITEM: u8 = 31;
+pub const SET_AND_VERIFY_SIZED_COLLECTION_ITEM: u8 = 32;
+pub const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+pub const CREATE: u8 = 42;
+pub const MINT: u8 = 43;
+pub const DELEGATE: u8 = 44;
+pub const REVOKE: u8 = 45;
+pub const TRANSFER: u8 = 49;
+pub const VERIFY: u8 = 52;
+pub const UNVERIFY: u8 = 53;
+pub const LAST_TAG: u8 = 55;
+
+/// Arguments of the `Delegate` instruction. The trailing authorization data is not needed and
+/// is left unread.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum DelegateArgs {
+    CollectionV1,
+    SaleV1 { amount: u64 },
+    TransferV1 { amount: u64 },
+    DataV1,
+    UtilityV1 { amount: u64 },
+    StakingV1 { amount: u64 },
+    StandardV1 { amount: u64 },
+    LockedTransferV1 { amount: u64, locked_address: Pubkey },
+    ProgrammableConfigV1,
+    AuthorityItemV1,
+    DataItemV1,
+    CollectionItemV1,
+    ProgrammableConfigItemV1,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum RevokeArgs {
+    CollectionV1,
+    SaleV1,
+    TransferV1,
+    DataV1,
+    UtilityV1,
+    StakingV1,
+    StandardV1,
+    LockedTransferV1,
+    ProgrammableConfigV1,
+    MigrationV1,
+    AuthorityItemV1,
+    DataItemV1,
+    CollectionItemV1,
+    ProgrammableConfigItemV1,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum VerificationArgs {
+    CreatorV1,
+    CollectionV1,
+}
+
+/// Instructions supported by the Metadata program.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetadataInstruction {
+    /// Create Metadata object. Newer program versions reject it, but it is found throughout
+    /// history.
+    CreateMetadataAccount { data: Data, is_mutable: bool },
+
+    /// Update a Metadata
+    UpdateMetadataAccount {
+        data: Option<Data>,
+        update_authority: Option<Pubkey>,
+        primary_sale_happened: Option<bool>,
+    },
+
+    /// Register a Metadata as a Master Edition V1, which means Editions can be minted.
+    CreateMasterEdition { max_supply: Option<u64> },
+
+    /// Given an authority token minted by the Restricted Mint of a Master Edition, and a brand
+    /// new non-metadata-ed mint with one token, make a new Metadata + Edition.
+    MintNewEditionFromMasterEditionViaToken { edition: u64 },
+
+    /// Update a Metadata with is_mutable as a parameter
+    UpdateMetadataAccountV2 {
+        data: Option<DataV2>,
+        update_authority: Option<Pubkey>,
+        primary_sale_happened: Option<bool>,
+        is_mutable: Option<bool>,
+    },
+
+    /// Create Metadata object.
+    CreateMetadataAccountV2 { data: DataV2, is_mutable: bool },
+
+    /// Register a Metadata as a Master Edition V2, which means Edition V2s can be minted.
+    CreateMasterEditionV3 { max_supply: Option<u64> },
+
+    /// If a MetadataAccount Has a Collection allow the UpdateAuthority of the Collection to
+    /// Verify the NFT Belongs in the Collection.
+    VerifyCollection,
+
+    /// If a MetadataAccount Has a Collection allow an Authority of the Collection to unverify
+    /// an NFT in a Collection.
+    UnverifyCollection,
+
+    /// Allows the update authority of a collection to set and verify an NFT in one go.
+    SetAndVerifyCollection,
+
+    /// Verify an NFT belonging to a sized collection.
+    VerifySizedCollectionItem,
+
+    /// Unverify an NFT belonging to a sized collection.
+    UnverifySizedCollectionItem,
+
+    /// Set and verify an NFT belonging to a sized collection.
+    SetAndVerifySizedCollectionItem,
+
+    /// Create Metadata object.
+    CreateMetadataAccountV3 {
+        data: DataV2,
+        is_mutable: bool,
+        collection_details: Option<CollectionDetails>,
+    },
+
+    /// Creates the metadata and associated accounts for a new or existing mint account.
+    Create {
+        asset_data: AssetData,
+        decimals: Option<u8>,
+        print_supply: Option<PrintSupply>,
+    },
+
+    /// Mints tokens from a mint account into the specified token account.
+    Mint { amount: u64 },
+
+    /// Creates a delegate for an asset.
+    Delegate(DelegateArgs),
+
+    /// Revokes a delegate.
+    Revoke(RevokeArgs),
+
+    /// Transfer tokens from one account to another.
+    Transfer { amount: u64 },
+
+    /// Verifies a creator or collection for an asset.
+    Verify(VerificationArgs),
+
+    /// Unverifies a creator or collection for an asset.
+    Unverify(VerificationArgs),
+
+    /// Any other instruction, identified by its tag only.
+    Other(u8),
+}
+
+impl MetadataInstruction {
+    /// Unpacks a byte buffer into a MetadataInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match tag {
+            CREATE_METADATA_ACCOUNT => {
+                let (data, is_mutable) = unpack_args(rest)?;
+                Self::CreateMetadataAccount { data, is_mutable }
+            }
+            UPDATE_METADATA_ACCOUNT => {
+                let (data, update_authority, primary_sale_happened) = unpack_args(rest)?;
+                Self::UpdateMetadataAccount {
+                    data,
+                    update_authority,
+                    primary_sale_happened,
+                }
+            }
+            CREATE_MASTER_EDITION => Self::CreateMasterEdition {
+                max_supply: unpack_args(rest)?,
+            },
+            MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN => {
+                Self::MintNewEditionFromMasterEditionViaToken {
+                    edition: unpack_args(rest)?,
+                }
+            }
+            UPDATE_METADATA_ACCOUNT_V2 => {
+                let (data, update_authority, primary_sale_happened, is_mutable) =
+                    unpack_args(rest)?;
+                Self::UpdateMetadataAccountV2 {
+                    data,
+                    update_authority,
+                    primary_sale_happened,
+                    is_mutable,
+                }
+            }
+            CREATE_METADATA_ACCOUNT_V2 => {
+                let (data, is_mutable) = unpack_args(rest)?;
+                Self::CreateMetadataAccountV2 { data, is_mutable }
+            }
+            CREATE_MASTER_EDITION_V3 => Self::CreateMasterEditionV3 {
+                max_supply: unpack_args(rest)?,
+            },
+            VERIFY_COLLECTION => Self::VerifyCollection,
+            UNVERIFY_COLLECTION => Self::UnverifyCollection,
+            SET_AND_VERIFY_COLLECTION => Self::SetAndVerifyCollection,
+            VERIFY_SIZED_COLLECTION_ITEM => Self::VerifySizedCollectionItem,
+            UNVERIFY_SIZED_COLLECTION_ITEM => Self::UnverifySizedCollectionItem,
+            SET_AND_VERIFY_SIZED_COLLECTION_ITEM => Self::SetAndVerifySizedCollectionItem,
+            CREATE_METADATA_ACCOUNT_V3 => {
+                let (data, is_mutable, collection_details) = unpack_args(rest)?;
+                Self::CreateMetadataAccountV3 {
+                    data,
+                    is_mutable,
+                    collection_details,
+                }
+            }
+            CREATE => {
+                // CreateArgs only has a V1 variant so far.
+                let (version, asset_data, decimals, print_supply): (u8, _, _, _) =
+                    unpack_args(rest)?;
+                if version != 0 {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                Self::Create {
+                    asset_data,
+                    decimals,
+                    print_supply,
+                }
+            }
+            MINT | TRANSFER => {
+                // MintArgs and TransferArgs only have a V1 variant so far, led by the amount.
+                let (version, amount): (u8, u64) = unpack_args(rest)?;
+                if version != 0 {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                if tag == MINT {
+                    Self::Mint { amount }
+                } else {
+                    Self::Transfer { amount }
+                }
+            }
+            DELEGATE => Self::Delegate(unpack_args(rest)?),
+            REVOKE => Self::Revoke(unpack_args(rest)?),
+            VERIFY => Self::Verify(unpack_args(rest)?),
+            UNVERIFY => Self::Unverify(unpack_args(rest)?),
+            _ if tag <= LAST_TAG => Self::Other(tag),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::programs::token_metadata::state::{Creator, TokenStandard};
+
+    fn data_v2() -> DataV2 {
+        DataV2 {
+            name: "Degen Ape".to_string(),
+            symbol: "DAPE".to_string(),
+            uri: "https://arweave.net/ape".to_string(),
+            seller_fee_basis_points: 420,
+            creators: Some(vec![Creator {
+                address: Pubkey::new(&[1u8; 32]),
+                verified: true,
+                share: 100,
+            }]),
+            collection: None,
+            uses: None,
+        }
+    }
+
+    #[test]
+    fn test_unpack_create_metadata_account_v3() {
+        let collection_details = Some(CollectionDetails::V1 { size: 10_000 });
+        let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+        data.extend_from_slice(
+            &(data_v2(), true, collection_details.clone())
+                .try_to_vec()
+                .unwrap(),
+        );
+        assert_eq!(
+            MetadataInstruction::unpack(&data).unwrap(),
+            MetadataInstruction::CreateMetadataAccountV3 {
+                data: data_v2(),
+                is_mutable: true,
+                collection_details,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_update_metadata_account_v2() {
+        let update_authority = Some(Pubkey::new(&[2u8; 32]));
+        let mut data = vec![UPDATE_METADATA_ACCOUNT_V2];
+        data.extend_from_slice(
+            &(Some(data_v2()), update_authority, Some(true), None::<bool>)
+                .try_to_vec()
+                .unwrap(),
+        );
+        assert_eq!(
+            MetadataInstruction::unpack(&data).unwrap(),
+            MetadataInstruction::UpdateMetadataAccountV2 {
+                data: Some(data_v2()),
+                update_authority,
+                primary_sale_happened: Some(true),
+                is_mutable: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unpack_create() {
+        let asset_data = AssetData {
+            name: "Degen Ape".to_string(),
+            symbol: "DAPE".to_string(),
+            uri: "https://arweave.net/ape".to_string(),
+            seller_fee_basis_points: 420,
+            creators: None,
+            primary_sale_happened: false,
+            is_mutable: true,
+            token_standard: TokenStandard::ProgrammableNonFungible,
+            collection: None,
+            uses: None,
+            collection_details: None,
+            rule_set: Some(Pubkey::new(&[3u8; 32])),
+        };
+        let mut data = vec![CREATE, 0];
+        data.extend_from_slice(
+            &(asset_data.clone(), Some(0u8), Some(PrintSupply::Zero))
+                .try_to_vec()
+                .unwrap(),
+        );
+        assert_eq!(
+            MetadataInstruction::unpack(&data).unwrap(),
+            MetadataInstruction::Create {
+                asset_data,
+                decimals: Some(0),
+                print_supply: Some(PrintSupply::Zero),
+            }
+        );
+
+        // Only CreateArgs::V1 is known.
+        data[1] = 1;
+        assert!(MetadataInstruction::unpack(&data).is_err());
+    }
+
+    #[test]
+    fn test_unpack_mint_and_transfer() {
+        // The trailing byte is the absent authorization data, which is left unread.
+        let mut data = vec![MINT, 0];
+        data.extend_from_slice(&5u64.to_le_bytes());
+        data.push(0);
+        assert_eq!(
+            MetadataInstruction::unpack(&data).unwrap(),
+            MetadataInstruction::Mint { amount: 5 }
+        );
+
+        data[0] = TRANSFER;
+        assert_eq!(
+            MetadataInstruction::unpack(&data).unwrap(),
+            MetadataInstruction::Transfer { amount: 5 }
+        );
+    }
+
+    #[test]
+    fn test_unpack_other() {
+        assert_eq!(
+            MetadataInstruction::unpack(&[LAST_TAG]).unwrap(),
+            MetadataInstruction::Other(LAST_TAG)
+        );
+        assert!(MetadataInstruction::unpack(&[LAST_TAG + 1]).is_err());
+    }
+}
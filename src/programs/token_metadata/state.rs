@@ -0,0 +1,147 @@
+//! State types carried by instructions
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    // In percentages, NOT basis points ;) Watch out!
+    pub share: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Data {
+    /// The name of the asset
+    pub name: String,
+    /// The symbol for the asset
+    pub symbol: String,
+    /// URI pointing to JSON representing the asset
+    pub uri: String,
+    /// Royalty basis points that goes to creators in secondary sales (0-10000)
+    pub seller_fee_basis_points: u16,
+    /// Array of creators, optional
+    pub creators: Option<Vec<Creator>>,
+}
+
+impl Data {
+    pub fn to_v2(&self) -> DataV2 {
+        DataV2 {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            creators: self.creators.clone(),
+            collection: None,
+            uses: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct DataV2 {
+    /// The name of the asset
+    pub name: String,
+    /// The symbol for the asset
+    pub symbol: String,
+    /// URI pointing to JSON representing the asset
+    pub uri: String,
+    /// Royalty basis points that goes to creators in secondary sales (0-10000)
+    pub seller_fee_basis_points: u16,
+    /// Array of creators, optional
+    pub creators: Option<Vec<Creator>>,
+    /// Collection
+    pub collection: Option<Collection>,
+    /// Uses
+    pub uses: Option<Uses>,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Collection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum CollectionDetails {
+    V1 { size: u64 },
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum UseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Uses {
+    pub use_method: UseMethod,
+    pub remaining: u64,
+    pub total: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum TokenStandard {
+    NonFungible,                    // This is a master edition
+    FungibleAsset,                  // A token with metadata that can also have attributes
+    Fungible,                       // A token with simple metadata
+    NonFungibleEdition,             // This is a limited edition
+    ProgrammableNonFungible,        // NonFungible with programmable configuration
+    ProgrammableNonFungibleEdition, // NonFungible with programmable configuration
+}
+
+/// Configuration for the print supply of a master edition.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub enum PrintSupply {
+    /// The asset does not have any prints.
+    Zero,
+    /// The asset has a limited amount of prints.
+    Limited(u64),
+    /// The asset has an unlimited amount of prints.
+    Unlimited,
+}
+
+/// Data representation of an asset.
+#[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct AssetData {
+    /// The name of the asset.
+    pub name: String,
+    /// The symbol for the asset.
+    pub symbol: String,
+    /// URI pointing to JSON representing the asset.
+    pub uri: String,
+    /// Royalty basis points that goes to creators in secondary sales (0-10000).
+    pub seller_fee_basis_points: u16,
+    /// Array of creators.
+    pub creators: Option<Vec<Creator>>,
+    // Immutable, once flipped, all sales of this metadata are considered secondary.
+    pub primary_sale_happened: bool,
+    // Whether or not the data struct is mutable (default is not).
+    pub is_mutable: bool,
+    /// Type of the token.
+    pub token_standard: TokenStandard,
+    /// Collection information.
+    pub collection: Option<Collection>,
+    /// Uses information.
+    pub uses: Option<Uses>,
+    /// Collection item details.
+    pub collection_details: Option<CollectionDetails>,
+    /// Programmable rule set for the asset.
+    pub rule_set: Option<Pubkey>,
+}
+
+impl AssetData {
+    pub fn to_data_v2(&self) -> DataV2 {
+        DataV2 {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            creators: self.creators.clone(),
+            collection: self.collection.clone(),
+            uses: self.uses.clone(),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Instruction types
+
+use solana_program::program_error::ProgramError;
+
+use crate::programs::anchor::{unpack_args, unpack_discriminator, Discriminator};
+
+pub const INITIALIZE_POOL: Discriminator = [95, 180, 10, 172, 84, 174, 232, 40];
+pub const OPEN_POSITION: Discriminator = [135, 128, 47, 77, 15, 152, 240, 49];
+pub const OPEN_POSITION_WITH_METADATA: Discriminator = [242, 29, 134, 48, 58, 110, 14, 60];
+pub const INCREASE_LIQUIDITY: Discriminator = [46, 156, 243, 118, 13, 205, 251, 178];
+pub const DECREASE_LIQUIDITY: Discriminator = [160, 38, 208, 111, 104, 91, 44, 1];
+pub const SWAP: Discriminator = [248, 198, 158, 145, 225, 117, 135, 200];
+pub const TWO_HOP_SWAP: Discriminator = [195, 96, 237, 108, 68, 162, 219, 230];
+pub const COLLECT_FEES: Discriminator = [164, 152, 207, 99, 30, 186, 19, 182];
+pub const COLLECT_REWARD: Discriminator = [70, 5, 132, 87, 86, 235, 177, 34];
+pub const CLOSE_POSITION: Discriminator = [123, 134, 81, 0, 49, 68, 98, 98];
+pub const UPDATE_FEES_AND_REWARDS: Discriminator = [154, 230, 250, 13, 236, 209, 75, 223];
+
+/// Instructions supported by the Whirlpool program. PDA bumps passed as arguments are not kept.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WhirlpoolInstruction {
+    /// Initializes a Whirlpool account.
+    InitializePool {
+        tick_spacing: u16,
+        initial_sqrt_price: u128,
+    },
+
+    /// Open a position in a Whirlpool. A unique token will be minted to represent the position.
+    OpenPosition {
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    },
+
+    /// Open a position in a Whirlpool, with Metaplex metadata on the position token.
+    OpenPositionWithMetadata {
+        tick_lower_index: i32,
+        tick_upper_index: i32,
+    },
+
+    /// Add liquidity to a position in the Whirlpool.
+    IncreaseLiquidity {
+        liquidity_amount: u128,
+        token_max_a: u64,
+        token_max_b: u64,
+    },
+
+    /// Withdraw liquidity from a position in the Whirlpool.
+    DecreaseLiquidity {
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+    },
+
+    /// Perform a swap in this Whirlpool.
+    Swap {
+        amount: u64,
+        other_amount_threshold: u64,
+        sqrt_price_limit: u128,
+        amount_specified_is_input: bool,
+        a_to_b: bool,
+    },
+
+    /// Perform a two-hop swap across two Whirlpools.
+    TwoHopSwap {
+        amount: u64,
+        other_amount_threshold: u64,
+        amount_specified_is_input: bool,
+        a_to_b_one: bool,
+        a_to_b_two: bool,
+        sqrt_price_limit_one: u128,
+        sqrt_price_limit_two: u128,
+    },
+
+    /// Collect fees accrued for this position.
+    CollectFees,
+
+    /// Collect rewards accrued for this position.
+    CollectReward { reward_index: u8 },
+
+    /// Close a position in a Whirlpool. Burns the position token.
+    ClosePosition,
+
+    /// Update the accrued fees and rewards for a position.
+    UpdateFeesAndRewards,
+}
+
+impl WhirlpoolInstruction {
+    /// Unpacks a byte buffer into a WhirlpoolInstruction
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (discriminator, rest) = unpack_discriminator(input)?;
+        Ok(match discriminator {
+            INITIALIZE_POOL => {
+                let (_whirlpool_bump, tick_spacing, initial_sqrt_price): (u8, _, _) =
+                    unpack_args(rest)?;
+                Self::InitializePool {
+                    tick_spacing,
+                    initial_sqrt_price,
+                }
+            }
+            OPEN_POSITION => {
+                let (_position_bump, tick_lower_index, tick_upper_index): (u8, _, _) =
+                    unpack_args(rest)?;
+                Self::OpenPosition {
+                    tick_lower_index,
+                    tick_upper_index,
+                }
+            }
+            OPEN_POSITION_WITH_METADATA => {
+                let (_position_bump, _metadata_bump, tick_lower_index, tick_upper_index): (
+                    u8,
+                    u8,
+                    _,
+                    _,
+                ) = unpack_args(rest)?;
+                Self::OpenPositionWithMetadata {
+                    tick_lower_index,
+                    tick_upper_index,
+                }
+            }
+            INCREASE_LIQUIDITY => {
+                let (liquidity_amount, token_max_a, token_max_b) = unpack_args(rest)?;
+                Self::IncreaseLiquidity {
+                    liquidity_amount,
+                    token_max_a,
+                    token_max_b,
+                }
+            }
+            DECREASE_LIQUIDITY => {
+                let (liquidity_amount, token_min_a, token_min_b) = unpack_args(rest)?;
+                Self::DecreaseLiquidity {
+                    liquidity_amount,
+                    token_min_a,
+                    token_min_b,
+                }
+            }
+            SWAP => {
+                let (
+                    amount,
+                    other_amount_threshold,
+                    sqrt_price_limit,
+                    amount_specified_is_input,
+                    a_to_b,
+                ) = unpack_args(rest)?;
+                Self::Swap {
+                    amount,
+                    other_amount_threshold,
+                    sqrt_price_limit,
+                    amount_specified_is_input,
+                    a_to_b,
+                }
+            }
+            TWO_HOP_SWAP => {
+                let (
+                    amount,
+                    other_amount_threshold,
+                    amount_specified_is_input,
+                    a_to_b_one,
+                    a_to_b_two,
+                    sqrt_price_limit_one,
+                    sqrt_price_limit_two,
+                ) = unpack_args(rest)?;
+                Self::TwoHopSwap {
+                    amount,
+                    other_amount_threshold,
+                    amount_specified_is_input,
+                    a_to_b_one,
+                    a_to_b_two,
+                    sqrt_price_limit_one,
+                    sqrt_price_limit_two,
+                }
+            }
+            COLLECT_FEES => Self::CollectFees,
+            COLLECT_REWARD => Self::CollectReward {
+                reward_index: unpack_args(rest)?,
+            },
+            CLOSE_POSITION => Self::ClosePosition,
+            UPDATE_FEES_AND_REWARDS => Self::UpdateFeesAndRewards,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+}
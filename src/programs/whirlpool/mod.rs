@@ -0,0 +1,3 @@
+//! Vendored whirlpool definitions, trimmed to what is needed to interpret its instructions.
+
+pub mod instruction;
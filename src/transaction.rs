@@ -0,0 +1,363 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use serde::{Serialize, Deserialize};
+use solana_sdk::hash::Hash;
+use solana_sdk::instruction::CompiledInstruction;
+use solana_sdk::message::{Message, MessageHeader};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction as SolanaTransaction;
+use solana_transaction_status::{
+    EncodedConfirmedTransaction, EncodedTransaction, UiInnerInstructions, UiInstruction,
+    UiMessage, UiParsedInstruction, UiTransaction,
+};
+use thiserror::Error;
+
+use crate::Instruction;
+
+/// Errors that may be returned when converting an encoded transaction into instructions.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum TransactionError {
+    /// The transaction is not in an encoding we can decode, or one of its signatures, account keys
+    /// or its blockhash is malformed.
+    #[error("Transaction could not be decoded from its encoding")]
+    UndecodableTransaction,
+    /// The transaction has no signatures to derive its hash from.
+    #[error("Transaction has no signatures")]
+    MissingSignature,
+    /// An instruction refers to a program outside of the message's account keys.
+    #[error("Program id index {0} is out of bounds")]
+    ProgramIdIndexOutOfBounds(u8),
+    /// An inner instruction refers to a top-level instruction that does not exist.
+    #[error("Inner instruction parent index {0} is out of bounds")]
+    ParentIndexOutOfBounds(u8),
+    /// The instruction was fully parsed by the RPC node and no longer carries its data.
+    #[error("Instruction was returned in jsonParsed form without raw data")]
+    ParsedInstruction,
+    /// The inner instruction was fully parsed by the RPC node and no longer carries its data.
+    #[error("Inner instruction was returned in jsonParsed form without raw data")]
+    ParsedInnerInstruction,
+    /// The instruction's data is not valid base58.
+    #[error("Instruction data is not valid base58")]
+    InvalidInstructionData,
+    /// The inner instruction's data is not valid base58.
+    #[error("Inner instruction data is not valid base58")]
+    InvalidInnerInstructionData,
+}
+
+/// A transaction broken down into what `process` expects.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Transaction {
+    // Every instruction of the transaction, top-level ones first, followed by inner instructions.
+    pub instructions: Vec<Instruction>,
+    // The top-level instructions as they appear in the message, required by the secp256k1 program.
+    pub compiled_instructions: Vec<CompiledInstruction>,
+}
+
+impl Transaction {
+    /// Flattens a decoded transaction and its inner instructions (if any).
+    ///
+    /// Top-level instructions take their index in the message as `tx_instruction_id` and have a
+    /// `parent_index` of -1. Inner instructions are numbered after the top-level ones, in the
+    /// order returned by the node, and take the index of the top-level instruction that invoked
+    /// them as their `parent_index`.
+    pub fn new(
+        transaction: &SolanaTransaction,
+        inner_instructions: Option<&[UiInnerInstructions]>,
+        timestamp: i64,
+    ) -> Result<Self, TransactionError> {
+        let transaction_hash = transaction.signatures.first()
+            .ok_or(TransactionError::MissingSignature)?
+            .to_string();
+        let account_keys = &transaction.message.account_keys;
+        let compiled_instructions = transaction.message.instructions.clone();
+
+        let mut instructions = Vec::new();
+        for (idx, ci) in compiled_instructions.iter().enumerate() {
+            let program = account_keys.get(ci.program_id_index as usize)
+                .ok_or(TransactionError::ProgramIdIndexOutOfBounds(ci.program_id_index))?;
+
+            instructions.push(Instruction {
+                tx_instruction_id: idx as i16,
+                transaction_hash: transaction_hash.clone(),
+                program: program.to_string(),
+                data: ci.data.clone(),
+                parent_index: -1,
+                timestamp,
+            });
+        }
+
+        let mut tx_instruction_id = compiled_instructions.len() as i16;
+        for inner in inner_instructions.unwrap_or_default() {
+            if inner.index as usize >= compiled_instructions.len() {
+                return Err(TransactionError::ParentIndexOutOfBounds(inner.index));
+            }
+
+            for ui in &inner.instructions {
+                let (program, data) = match ui {
+                    UiInstruction::Compiled(uci) => {
+                        let program = account_keys.get(uci.program_id_index as usize)
+                            .ok_or(TransactionError::ProgramIdIndexOutOfBounds(
+                                uci.program_id_index))?;
+                        (program.to_string(), uci.data.clone())
+                    }
+                    UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(upi)) => {
+                        (upi.program_id.clone(), upi.data.clone())
+                    }
+                    UiInstruction::Parsed(UiParsedInstruction::Parsed(_)) => {
+                        return Err(TransactionError::ParsedInnerInstruction);
+                    }
+                };
+
+                instructions.push(Instruction {
+                    tx_instruction_id,
+                    transaction_hash: transaction_hash.clone(),
+                    program,
+                    data: bs58::decode(data).into_vec()
+                        .map_err(|_| TransactionError::InvalidInnerInstructionData)?,
+                    parent_index: inner.index as i16,
+                    timestamp,
+                });
+                tx_instruction_id += 1;
+            }
+        }
+
+        Ok(Transaction {
+            instructions,
+            compiled_instructions,
+        })
+    }
+}
+
+impl TryFrom<&SolanaTransaction> for Transaction {
+    type Error = TransactionError;
+
+    fn try_from(transaction: &SolanaTransaction) -> Result<Self, Self::Error> {
+        Transaction::new(transaction, None, 0)
+    }
+}
+
+/// Accepts base58 or base64 wire transactions, as well as the json and jsonParsed encodings.
+impl TryFrom<&EncodedTransaction> for Transaction {
+    type Error = TransactionError;
+
+    fn try_from(encoded_transaction: &EncodedTransaction) -> Result<Self, Self::Error> {
+        let transaction = decode_transaction(encoded_transaction)?;
+
+        Transaction::new(&transaction, None, 0)
+    }
+}
+
+/// Accepts a `getConfirmedTransaction` response, including its inner instructions. The block
+/// time is used as the timestamp of every instruction.
+impl TryFrom<&EncodedConfirmedTransaction> for Transaction {
+    type Error = TransactionError;
+
+    fn try_from(confirmed_transaction: &EncodedConfirmedTransaction) -> Result<Self, Self::Error> {
+        let transaction = decode_transaction(&confirmed_transaction.transaction.transaction)?;
+        let inner_instructions = confirmed_transaction.transaction.meta.as_ref()
+            .and_then(|meta| meta.inner_instructions.as_deref());
+
+        Transaction::new(&transaction, inner_instructions,
+                         confirmed_transaction.block_time.unwrap_or_default())
+    }
+}
+
+fn decode_transaction(
+    encoded_transaction: &EncodedTransaction,
+) -> Result<SolanaTransaction, TransactionError> {
+    match encoded_transaction {
+        EncodedTransaction::Json(ui_transaction) => decode_json_transaction(ui_transaction),
+        _ => encoded_transaction.decode().ok_or(TransactionError::UndecodableTransaction),
+    }
+}
+
+// The json encodings spell out the message, rebuild it so both take the same path as the binary
+// ones.
+fn decode_json_transaction(
+    ui_transaction: &UiTransaction,
+) -> Result<SolanaTransaction, TransactionError> {
+    let signatures = ui_transaction.signatures.iter()
+        .map(|signature| Signature::from_str(signature))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| TransactionError::UndecodableTransaction)?;
+
+    let message = match &ui_transaction.message {
+        UiMessage::Raw(raw_message) => {
+            let mut instructions = Vec::new();
+            for uci in &raw_message.instructions {
+                instructions.push(CompiledInstruction {
+                    program_id_index: uci.program_id_index,
+                    accounts: uci.accounts.clone(),
+                    data: bs58::decode(&uci.data).into_vec()
+                        .map_err(|_| TransactionError::InvalidInstructionData)?,
+                });
+            }
+
+            Message {
+                header: raw_message.header,
+                account_keys: parse_pubkeys(raw_message.account_keys.iter())?,
+                recent_blockhash: parse_hash(&raw_message.recent_blockhash)?,
+                instructions,
+            }
+        }
+        UiMessage::Parsed(parsed_message) => {
+            let account_keys = parse_pubkeys(
+                parsed_message.account_keys.iter().map(|account| &account.pubkey))?;
+            let header = MessageHeader {
+                num_required_signatures: parsed_message.account_keys.iter()
+                    .filter(|account| account.signer)
+                    .count() as u8,
+                num_readonly_signed_accounts: parsed_message.account_keys.iter()
+                    .filter(|account| account.signer && !account.writable)
+                    .count() as u8,
+                num_readonly_unsigned_accounts: parsed_message.account_keys.iter()
+                    .filter(|account| !account.signer && !account.writable)
+                    .count() as u8,
+            };
+            // Partially decoded instructions refer to accounts by key rather than by index.
+            let index_of = |key: &str| -> Result<u8, TransactionError> {
+                parsed_message.account_keys.iter()
+                    .position(|account| account.pubkey == key)
+                    .map(|index| index as u8)
+                    .ok_or(TransactionError::UndecodableTransaction)
+            };
+
+            let mut instructions = Vec::new();
+            for ui in &parsed_message.instructions {
+                let (program_id_index, accounts, data) = match ui {
+                    UiInstruction::Compiled(uci) => {
+                        (uci.program_id_index, uci.accounts.clone(), &uci.data)
+                    }
+                    UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(upi)) => {
+                        let accounts = upi.accounts.iter()
+                            .map(|account| index_of(account.as_str()))
+                            .collect::<Result<Vec<_>, _>>()?;
+                        (index_of(upi.program_id.as_str())?, accounts, &upi.data)
+                    }
+                    UiInstruction::Parsed(UiParsedInstruction::Parsed(_)) => {
+                        return Err(TransactionError::ParsedInstruction);
+                    }
+                };
+
+                instructions.push(CompiledInstruction {
+                    program_id_index,
+                    accounts,
+                    data: bs58::decode(data).into_vec()
+                        .map_err(|_| TransactionError::InvalidInstructionData)?,
+                });
+            }
+
+            Message {
+                header,
+                account_keys,
+                recent_blockhash: parse_hash(&parsed_message.recent_blockhash)?,
+                instructions,
+            }
+        }
+    };
+
+    Ok(SolanaTransaction {
+        signatures,
+        message,
+    })
+}
+
+fn parse_pubkeys<'a>(
+    keys: impl Iterator<Item = &'a String>,
+) -> Result<Vec<Pubkey>, TransactionError> {
+    keys.map(|key| Pubkey::from_str(key))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| TransactionError::UndecodableTransaction)
+}
+
+fn parse_hash(hash: &str) -> Result<Hash, TransactionError> {
+    Hash::from_str(hash).map_err(|_| TransactionError::UndecodableTransaction)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use solana_sdk::instruction::{AccountMeta, Instruction as SolanaInstruction};
+    use solana_transaction_status::{UiCompiledInstruction, UiTransactionEncoding};
+
+    fn transaction() -> SolanaTransaction {
+        let payer = Pubkey::new(&[1u8; 32]);
+        let program_id = Pubkey::new(&[2u8; 32]);
+        let message = Message::new(
+            &[SolanaInstruction::new_with_bytes(
+                program_id,
+                &[1, 2, 3],
+                vec![AccountMeta::new(payer, true)],
+            )],
+            Some(&payer),
+        );
+
+        SolanaTransaction::new_unsigned(message)
+    }
+
+    fn assert_converted(converted: &Transaction) {
+        assert_eq!(converted.compiled_instructions, transaction().message.instructions);
+        assert_eq!(converted.instructions.len(), 1);
+        assert_eq!(converted.instructions[0].tx_instruction_id, 0);
+        assert_eq!(converted.instructions[0].parent_index, -1);
+        assert_eq!(converted.instructions[0].program, Pubkey::new(&[2u8; 32]).to_string());
+        assert_eq!(converted.instructions[0].data, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_try_from_encoded_transaction() {
+        for encoding in vec![
+            UiTransactionEncoding::Base58,
+            UiTransactionEncoding::Base64,
+            UiTransactionEncoding::Json,
+            UiTransactionEncoding::JsonParsed,
+        ] {
+            let encoded_transaction = EncodedTransaction::encode(transaction(), encoding);
+            assert_converted(&Transaction::try_from(&encoded_transaction).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_try_from_malformed_json_transaction() {
+        let mut encoded_transaction =
+            EncodedTransaction::encode(transaction(), UiTransactionEncoding::Json);
+        if let EncodedTransaction::Json(ui_transaction) = &mut encoded_transaction {
+            ui_transaction.signatures[0] = "not a signature".to_string();
+        }
+
+        assert_eq!(
+            Transaction::try_from(&encoded_transaction).err(),
+            Some(TransactionError::UndecodableTransaction)
+        );
+    }
+
+    #[test]
+    fn test_new_with_inner_instructions() {
+        let inner_instructions = vec![UiInnerInstructions {
+            index: 0,
+            instructions: vec![UiInstruction::Compiled(UiCompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![0],
+                data: bs58::encode(&[4u8, 5]).into_string(),
+            })],
+        }];
+        let converted = Transaction::new(&transaction(), Some(&inner_instructions), 7).unwrap();
+
+        assert_eq!(converted.instructions.len(), 2);
+        assert_eq!(converted.instructions[1].tx_instruction_id, 1);
+        assert_eq!(converted.instructions[1].parent_index, 0);
+        assert_eq!(converted.instructions[1].data, vec![4, 5]);
+        assert_eq!(converted.instructions[1].timestamp, 7);
+
+        let orphans = vec![UiInnerInstructions {
+            index: 1,
+            instructions: vec![],
+        }];
+        assert_eq!(
+            Transaction::new(&transaction(), Some(&orphans), 7).err(),
+            Some(TransactionError::ParentIndexOutOfBounds(1))
+        );
+    }
+}